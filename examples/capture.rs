@@ -0,0 +1,59 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy_splash_screen::{
+    SplashAssetType, SplashCapture, SplashEndAction, SplashImageFit, SplashItem, SplashPlugin,
+    SplashScreen,
+};
+use bevy_tweening::EaseFunction;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, States, Hash, PartialEq, Eq)]
+enum ScreenStates {
+    #[default]
+    Splash,
+    Menu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<ScreenStates>()
+        .add_plugins(
+            SplashPlugin::new(ScreenStates::Splash, ScreenStates::Menu)
+                // Frames are written to `capture/00000.png`, `capture/00001.png`...
+                .capture(SplashCapture::new("capture", UVec2::new(640, 360)).with_fps(30))
+                .on_end(SplashEndAction::send_event(AppExit::Success))
+                .add_screen(SplashScreen {
+                    brands: vec![
+                        SplashItem {
+                            asset: SplashAssetType::SingleText(
+                                Text::from_section(
+                                    "Capture Test",
+                                    TextStyle {
+                                        font_size: 40.,
+                                        color: Color::WHITE,
+                                        ..default()
+                                    },
+                                ),
+                                "FiraSans-Bold.ttf".to_string(),
+                            ),
+                            ease_function: EaseFunction::QuarticInOut.into(),
+                            duration: Duration::from_secs_f32(2.),
+                            ..default()
+                        },
+                        SplashItem {
+                            asset: SplashAssetType::SingleImage("bevy_logo.png".to_string()),
+                            width: Val::Percent(60.),
+                            height: Val::Px(100.),
+                            ease_function: EaseFunction::QuinticInOut.into(),
+                            duration: Duration::from_secs_f32(2.),
+                            fit: SplashImageFit::Contain,
+                            ..default()
+                        },
+                    ],
+                    background_color: BackgroundColor(Color::BLACK),
+                    ..default()
+                }),
+        )
+        .run();
+}
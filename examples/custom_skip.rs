@@ -50,6 +50,7 @@ fn main() {
                         ease_function: EaseFunction::QuarticInOut.into(),
                         duration: Duration::from_secs(5),
                         is_static: false,
+                        ..default()
                     }],
                     background_color: BackgroundColor(Color::BLACK),
                     ..default()
@@ -73,6 +74,7 @@ fn main() {
                         ease_function: EaseFunction::QuarticInOut.into(),
                         duration: Duration::from_secs(5),
                         is_static: false,
+                        ..default()
                     }],
                     background_color: BackgroundColor(Color::BLACK),
                     ..default()
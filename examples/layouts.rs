@@ -1,6 +1,9 @@
 use bevy::color::palettes;
 use bevy::prelude::*;
-use bevy_splash_screen::{SplashAssetType, SplashItem, SplashPlugin, SplashScreen, SplashType};
+use bevy_splash_screen::{
+    SplashAnchor, SplashAssetType, SplashBreakpoint, SplashCamera, SplashItem, SplashPlugin,
+    SplashScreen, SplashStyle, SplashType,
+};
 use bevy_tweening::EaseFunction;
 use std::time::Duration;
 
@@ -17,7 +20,9 @@ fn main() {
         .init_state::<ScreenStates>()
         .add_plugins(
             SplashPlugin::new(ScreenStates::Splash, ScreenStates::Menu)
+                .camera(SplashCamera::spawn())
                 .skipable()
+                .safe_area(UiRect::all(Val::Percent(5.)))
                 .add_screen(SplashScreen {
                     brands: vec![
                         SplashItem {
@@ -57,6 +62,7 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
                         },
                         SplashItem {
                             asset: SplashAssetType::SingleText(
@@ -77,6 +83,24 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
+                        },
+                        SplashItem {
+                            asset: SplashAssetType::SingleText(
+                                Text::from_section(
+                                    "Powered by Bevy",
+                                    TextStyle {
+                                        font_size: 18.,
+                                        color: Color::WHITE.with_alpha(0.6),
+                                        ..default()
+                                    },
+                                ),
+                                "FiraSans-Bold.ttf".to_string(),
+                            ),
+                            duration: Duration::from_secs_f32(5.),
+                            anchor: Some(SplashAnchor::BottomRight),
+                            anchor_offset: (Val::Px(24.), Val::Px(24.)),
+                            ..default()
                         },
                     ],
                     splash_type: SplashType::List,
@@ -104,6 +128,7 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
                         },
                         SplashItem {
                             asset: SplashAssetType::SingleText(
@@ -124,6 +149,7 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(12.),
                             is_static: false,
+                            ..default()
                         },
                         SplashItem {
                             asset: SplashAssetType::SingleText(
@@ -144,6 +170,7 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
                         },
                         SplashItem {
                             asset: SplashAssetType::SingleText(
@@ -164,18 +191,26 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
                         },
                     ],
                     splash_type: SplashType::Grid,
                     wait_to_start: bevy_splash_screen::WaitScreenType::AfterEnd,
                     background_color: BackgroundColor(Color::BLACK),
+                    stagger: Duration::from_millis(500),
+                    style: SplashStyle::new(|style| {
+                        style.column_gap = Val::Px(32.);
+                        style.row_gap = Val::Px(32.);
+                    }),
+                    // Grid becomes a list on portrait windows
+                    breakpoints: vec![SplashBreakpoint {
+                        max_aspect_ratio: Some(1.),
+                        splash_type: Some(SplashType::List),
+                        brand_size: Some((Val::Percent(80.), Val::Px(100.))),
+                        ..default()
+                    }],
                     ..default()
                 }),
         )
-        .add_systems(Startup, create_scene)
         .run();
 }
-
-fn create_scene(mut cmd: Commands) {
-    cmd.spawn(Camera2dBundle::default());
-}
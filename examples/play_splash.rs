@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_splash_screen::{
+    PlaySplash, SplashAssetType, SplashCommandsExt, SplashItem, SplashScreen, SplashScreenEndEvent,
+    SplashSequencePlugin,
+};
+use bevy_tweening::EaseFunction;
+use std::time::Duration;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(SplashSequencePlugin)
+        .add_systems(Startup, create_scene)
+        .add_systems(Update, (play_chapter, chapter_end))
+        .run();
+}
+
+fn create_scene(mut cmd: Commands) {
+    cmd.spawn(Camera2dBundle::default());
+    cmd.spawn(TextBundle::from_section(
+        "Press Space to play the chapter title",
+        TextStyle {
+            font_size: 32.,
+            ..default()
+        },
+    ));
+}
+
+fn play_chapter(mut cmd: Commands, keys: Res<ButtonInput<KeyCode>>) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let entity = cmd.play_splash(PlaySplash {
+        screens: vec![SplashScreen {
+            brands: vec![SplashItem {
+                asset: SplashAssetType::SingleText(
+                    Text::from_sections([
+                        TextSection::new(
+                            "Chapter 2\n",
+                            TextStyle {
+                                font_size: 76.,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ),
+                        TextSection::new(
+                            "The Return",
+                            TextStyle {
+                                font_size: 38.,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ),
+                    ])
+                    .with_justify(JustifyText::Center),
+                    "FiraSans-Bold.ttf".to_string(),
+                ),
+                tint: Color::WHITE,
+                width: Val::Percent(40.),
+                height: Val::Px(150.),
+                ease_function: EaseFunction::QuarticInOut.into(),
+                duration: Duration::from_secs(2),
+                is_static: false,
+                ..default()
+            }],
+            background_color: BackgroundColor(Color::BLACK),
+            ..default()
+        }],
+        skipable: true,
+        ..default()
+    });
+    info!("Playing chapter title {entity:?}");
+}
+
+fn chapter_end(mut reader: EventReader<SplashScreenEndEvent>) {
+    for e in reader.read() {
+        info!("Chapter title {:?} ended, skipped: {}", e.entity, e.skipped);
+    }
+}
@@ -1,6 +1,9 @@
 use bevy::color::palettes;
 use bevy::prelude::*;
-use bevy_splash_screen::{SplashAssetType, SplashItem, SplashPlugin, SplashScreen};
+use bevy_splash_screen::{
+    SplashAssetType, SplashCamera, SplashItem, SplashPlugin, SplashScreen, SplashTextAnimation,
+    SplashTransition,
+};
 use bevy_tweening::EaseFunction;
 use std::time::Duration;
 
@@ -17,6 +20,7 @@ fn main() {
         .init_state::<ScreenStates>()
         .add_plugins(
             SplashPlugin::new(ScreenStates::Splash, ScreenStates::Menu)
+                .camera(SplashCamera::spawn())
                 .add_screen(SplashScreen {
                     brands: vec![SplashItem {
                         asset: SplashAssetType::SingleText(
@@ -55,6 +59,7 @@ fn main() {
                         ease_function: EaseFunction::QuarticInOut.into(),
                         duration: Duration::from_secs_f32(5.),
                         is_static: false,
+                        ..default()
                     }],
                     background_color: BackgroundColor(Color::BLACK),
                     ..default()
@@ -79,9 +84,12 @@ fn main() {
                         ease_function: EaseFunction::QuarticInOut.into(),
                         duration: Duration::from_secs_f32(5.),
                         is_static: false,
+                        text_animation: SplashTextAnimation::TypewriterWords,
+                        ..default()
                     }],
                     wait_to_start: bevy_splash_screen::WaitScreenType::AfterEnd,
                     background_color: BackgroundColor(Color::BLACK),
+                    transition: SplashTransition::SlideLeft(Duration::from_secs(1)),
                     ..default()
                 })
                 .add_screen(SplashScreen {
@@ -104,16 +112,13 @@ fn main() {
                         ease_function: EaseFunction::QuarticInOut.into(),
                         duration: Duration::from_secs_f32(5.),
                         is_static: false,
+                        ..default()
                     }],
                     wait_to_start: bevy_splash_screen::WaitScreenType::AfterEnd,
                     background_color: BackgroundColor(Color::WHITE),
+                    transition: SplashTransition::BoxWipe(Duration::from_secs(1)),
                     ..default()
                 }),
         )
-        .add_systems(Startup, create_scene)
         .run();
 }
-
-fn create_scene(mut cmd: Commands) {
-    cmd.spawn(Camera2dBundle::default());
-}
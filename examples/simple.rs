@@ -1,6 +1,8 @@
 use bevy::color::palettes;
 use bevy::prelude::*;
-use bevy_splash_screen::{SplashAssetType, SplashItem, SplashPlugin, SplashScreen};
+use bevy_splash_screen::{
+    SplashAssetType, SplashCamera, SplashImageFit, SplashItem, SplashPlugin, SplashScreen,
+};
 use bevy_tweening::EaseFunction;
 use std::time::Duration;
 
@@ -18,6 +20,7 @@ fn main() {
         .add_plugins(
             SplashPlugin::new(ScreenStates::Splash, ScreenStates::Menu)
                 .skipable()
+                .camera(SplashCamera::spawn())
                 .add_screen(SplashScreen {
                     brands: vec![
                         SplashItem {
@@ -57,6 +60,7 @@ fn main() {
                             ease_function: EaseFunction::QuarticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: false,
+                            ..default()
                         },
                         SplashItem {
                             asset: SplashAssetType::SingleImage("bevy_logo.png".to_string()),
@@ -66,16 +70,13 @@ fn main() {
                             ease_function: EaseFunction::QuinticInOut.into(),
                             duration: Duration::from_secs_f32(5.),
                             is_static: true,
+                            fit: SplashImageFit::Contain,
+                            ..default()
                         },
                     ],
                     background_color: BackgroundColor(Color::BLACK),
                     ..default()
                 }),
         )
-        .add_systems(Startup, create_scene)
         .run();
 }
-
-fn create_scene(mut cmd: Commands) {
-    cmd.spawn(Camera2dBundle::default());
-}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use bevy_splash_screen::{
+    SplashAssetType, SplashCamera, SplashItem, SplashPlugin, SplashRender, SplashScreen,
+};
+use bevy_tweening::EaseFunction;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, States, Hash, PartialEq, Eq)]
+enum ScreenStates {
+    #[default]
+    Splash,
+    Menu,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .init_state::<ScreenStates>()
+        .add_plugins(
+            SplashPlugin::new(ScreenStates::Splash, ScreenStates::Menu)
+                .skipable()
+                .render(SplashRender::World)
+                .camera(SplashCamera::spawn())
+                .add_screen(SplashScreen {
+                    brands: vec![
+                        SplashItem {
+                            asset: SplashAssetType::SingleText(
+                                Text::from_section(
+                                    "Made with",
+                                    TextStyle {
+                                        font_size: 40.,
+                                        color: Color::WHITE,
+                                        ..default()
+                                    },
+                                ),
+                                "FiraSans-Bold.ttf".to_string(),
+                            ),
+                            height: Val::Px(60.),
+                            ease_function: EaseFunction::QuarticInOut.into(),
+                            duration: Duration::from_secs(3),
+                            ..default()
+                        },
+                        SplashItem {
+                            asset: SplashAssetType::SingleImage("bevy_logo.png".to_string()),
+                            width: Val::Px(400.),
+                            height: Val::Px(100.),
+                            ease_function: EaseFunction::QuinticInOut.into(),
+                            duration: Duration::from_secs(3),
+                            ..default()
+                        },
+                    ],
+                    background_color: BackgroundColor(Color::BLACK),
+                    ..default()
+                }),
+        )
+        .run();
+}
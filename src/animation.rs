@@ -0,0 +1,327 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use bevy::asset::{LoadState, LoadedFolder};
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplashAnimationMode {
+    /// Stop on the last frame
+    Once,
+    #[default]
+    Loop,
+    /// Go forward and back
+    PingPong,
+}
+
+/// Grid of the sprite sheet and how it is played
+#[derive(Clone, Copy, Debug)]
+pub struct SplashSpriteSheet {
+    pub tile_size: UVec2,
+    pub columns: u32,
+    pub rows: u32,
+    pub padding: Option<UVec2>,
+    pub offset: Option<UVec2>,
+    pub fps: f32,
+    pub mode: SplashAnimationMode,
+}
+
+impl SplashSpriteSheet {
+    pub(crate) fn layout(&self) -> TextureAtlasLayout {
+        TextureAtlasLayout::from_grid(
+            self.tile_size,
+            self.columns,
+            self.rows,
+            self.padding,
+            self.offset,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SplashFramesSource {
+    /// Pattern like `logo_%04d.png`, first frame number and count of frames
+    Pattern(String, u32, u32),
+    /// Every image on the folder, sorted by name with the numbers
+    /// in order, `frame_2.png` before `frame_10.png`
+    Folder(String),
+}
+
+/// Flipbook of numbered images
+#[derive(Clone, Debug)]
+pub struct SplashImageSequence {
+    pub source: SplashFramesSource,
+    pub fps: f32,
+    pub mode: SplashAnimationMode,
+}
+
+impl SplashImageSequence {
+    pub(crate) fn load(&self, assets: &AssetServer) -> SplashImageFrames {
+        match &self.source {
+            SplashFramesSource::Pattern(pattern, first, count) => SplashImageFrames {
+                frames: (*first..first + count)
+                    .map(|i| assets.load(frame_path(pattern, i)))
+                    .collect(),
+                folder: None,
+                loaded: false,
+            },
+            SplashFramesSource::Folder(path) => SplashImageFrames {
+                frames: Vec::new(),
+                folder: Some(assets.load_folder(path.clone())),
+                loaded: false,
+            },
+        }
+    }
+}
+
+// Replace each `%d` or `%0Nd` of pattern with the frame number, `%%` is a `%`
+fn frame_path(pattern: &str, frame: u32) -> String {
+    let mut path = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            path.push(c);
+            continue;
+        }
+        if chars.next_if_eq(&'%').is_some() {
+            path.push('%');
+            continue;
+        }
+        let mut spec = String::new();
+        while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+            spec.push(digit);
+        }
+        // Anything else is kept as written
+        if chars.next_if_eq(&'d').is_none() {
+            path.push('%');
+            path.push_str(&spec);
+            continue;
+        }
+        let width = spec.parse::<usize>().unwrap_or(0);
+        if spec.starts_with('0') {
+            path.push_str(&format!("{frame:0width$}"));
+        } else {
+            path.push_str(&format!("{frame:>width$}"));
+        }
+    }
+    path
+}
+
+// Order of names with their numbers compared by value
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(x), Some(y)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        let ordering = if x.is_ascii_digit() && y.is_ascii_digit() {
+            let (number_a, rest_a) = split_number(a);
+            let (number_b, rest_b) = split_number(b);
+            (a, b) = (rest_a, rest_b);
+            let (trim_a, trim_b) = (
+                number_a.trim_start_matches('0'),
+                number_b.trim_start_matches('0'),
+            );
+            trim_a
+                .len()
+                .cmp(&trim_b.len())
+                .then_with(|| trim_a.cmp(trim_b))
+                .then_with(|| number_a.len().cmp(&number_b.len()))
+        } else {
+            (a, b) = (&a[x.len_utf8()..], &b[y.len_utf8()..]);
+            x.cmp(&y)
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn split_number(text: &str) -> (&str, &str) {
+    text.split_at(
+        text.find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len()),
+    )
+}
+
+// Images of an image sequence brand, folder ones are filled once loaded
+#[derive(Component)]
+pub(crate) struct SplashImageFrames {
+    pub(crate) frames: Vec<Handle<Image>>,
+    folder: Option<Handle<LoadedFolder>>,
+    // all the frames are loaded, the texture is kept until then
+    loaded: bool,
+}
+
+// Frame animation of a brand, starts when the brand fade in
+#[derive(Component)]
+pub(crate) struct SplashFrames {
+    pub(crate) frames: usize,
+    pub(crate) fps: f32,
+    pub(crate) mode: SplashAnimationMode,
+    pub(crate) delay: Duration,
+    pub(crate) elapsed: Duration,
+}
+
+impl SplashFrames {
+    pub(crate) fn frame(&self) -> usize {
+        let last = self.frames.saturating_sub(1);
+        let time = self.elapsed.saturating_sub(self.delay).as_secs_f32();
+        let frame = (time * self.fps) as usize;
+
+        match self.mode {
+            SplashAnimationMode::Once => frame.min(last),
+            SplashAnimationMode::Loop => frame % self.frames.max(1),
+            SplashAnimationMode::PingPong => {
+                let period = (last * 2).max(1);
+                let frame = frame % period;
+                if frame <= last {
+                    frame
+                } else {
+                    period - frame
+                }
+            }
+        }
+    }
+}
+
+// Node or sprite texture
+#[cfg(feature = "ui")]
+type FramesTexture = AnyOf<(&'static mut UiImage, &'static mut Handle<Image>)>;
+#[cfg(not(feature = "ui"))]
+type FramesTexture = &'static mut Handle<Image>;
+
+type FramesQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut SplashFrames,
+        Option<&'static mut TextureAtlas>,
+        Option<&'static mut SplashImageFrames>,
+        FramesTexture,
+    ),
+>;
+
+//
+// Advance sprite sheet and image sequence brands
+//
+pub(crate) fn animate_frames(
+    time: Res<Time>,
+    assets: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    mut brands: FramesQuery,
+) {
+    for (mut frames, atlas, mut images, texture) in brands.iter_mut() {
+        #[cfg(feature = "ui")]
+        let (image, sprite) = texture;
+        #[cfg(not(feature = "ui"))]
+        let sprite = Some(texture);
+        // On the time of the brand even while the frames load
+        frames.elapsed += time.delta();
+
+        if let Some(images) = images.as_mut().filter(|i| !i.loaded) {
+            if let Some(folder) = images.folder.as_ref().and_then(|f| folders.get(f)) {
+                let mut handles = folder
+                    .handles
+                    .iter()
+                    .filter_map(|h| h.clone().try_typed::<Image>().ok())
+                    .collect::<Vec<_>>();
+                handles.sort_by(|a, b| {
+                    let name =
+                        |h: &Handle<Image>| h.path().map(|p| p.to_string()).unwrap_or_default();
+                    natural_cmp(&name(a), &name(b))
+                });
+                frames.frames = handles.len();
+                images.frames = handles;
+                images.folder = None;
+            }
+            // Failed frames do not hold the animation forever
+            images.loaded = images.folder.is_none()
+                && images.frames.iter().all(|frame| {
+                    assets.is_loaded_with_dependencies(frame)
+                        || matches!(assets.load_state(frame), LoadState::Failed(_))
+                });
+        }
+
+        if let Some(mut atlas) = atlas {
+            let frame = frames.frame();
+            if atlas.index != frame {
+                atlas.index = frame;
+            }
+        }
+
+        let Some(images) = images.filter(|i| i.loaded) else {
+            continue;
+        };
+        let Some(texture) = images.frames.get(frames.frame()) else {
+            continue;
+        };
+        #[cfg(feature = "ui")]
+        if let Some(mut image) = image.filter(|i| i.texture != *texture) {
+            image.texture = texture.clone();
+        }
+        if let Some(mut sprite) = sprite.filter(|s| **s != *texture) {
+            *sprite = texture.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_path_number() {
+        assert_eq!(frame_path("logo_%d.png", 7), "logo_7.png");
+        assert_eq!(frame_path("logo_%d.png", 120), "logo_120.png");
+    }
+
+    #[test]
+    fn frame_path_padding() {
+        assert_eq!(frame_path("logo_%04d.png", 7), "logo_0007.png");
+        assert_eq!(frame_path("logo_%04d.png", 12345), "logo_12345.png");
+        assert_eq!(frame_path("logo_%3d.png", 7), "logo_  7.png");
+    }
+
+    #[test]
+    fn frame_path_percent() {
+        assert_eq!(frame_path("100%%/logo_%d.png", 3), "100%/logo_3.png");
+        assert_eq!(frame_path("%%d_%d", 3), "%d_3");
+    }
+
+    #[test]
+    fn frame_path_malformed() {
+        assert_eq!(frame_path("logo.png", 3), "logo.png");
+        assert_eq!(frame_path("logo_%x.png", 3), "logo_%x.png");
+        assert_eq!(frame_path("logo_%04.png", 3), "logo_%04.png");
+        assert_eq!(frame_path("logo_%", 3), "logo_%");
+    }
+
+    #[test]
+    fn frame_on_brand_time() {
+        let frames = |mode, elapsed| SplashFrames {
+            frames: 4,
+            fps: 10.,
+            mode,
+            delay: Duration::from_secs(1),
+            elapsed: Duration::from_millis(elapsed),
+        };
+        assert_eq!(frames(SplashAnimationMode::Once, 500).frame(), 0);
+        assert_eq!(frames(SplashAnimationMode::Once, 1250).frame(), 2);
+        assert_eq!(frames(SplashAnimationMode::Once, 9000).frame(), 3);
+        assert_eq!(frames(SplashAnimationMode::Loop, 1450).frame(), 0);
+        assert_eq!(frames(SplashAnimationMode::PingPong, 1450).frame(), 2);
+    }
+
+    #[test]
+    fn natural_order() {
+        let mut names = vec!["frame_10.png", "frame_2.png", "frame_1.png", "frame_02.png"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["frame_1.png", "frame_2.png", "frame_02.png", "frame_10.png"]
+        );
+        assert_eq!(natural_cmp("a", "b"), Ordering::Less);
+        assert_eq!(natural_cmp("frame", "frame_1"), Ordering::Less);
+    }
+}
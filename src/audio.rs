@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use bevy::audio::Volume;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy_tweening::TweenCompleted;
+
+use crate::systems::SplashSequence;
+
+/// Sound played with a brand
+#[derive(Clone, Debug)]
+pub struct SplashAudio {
+    pub path: String,
+    pub volume: f32,
+    /// Delay after the brand starts to fade in
+    pub offset: Duration,
+    /// Fade out when the brand ends or the splash is skipped
+    pub fade_out: Duration,
+}
+
+impl SplashAudio {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            volume: 1.,
+            offset: Duration::ZERO,
+            fade_out: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Music playing during the whole splash
+#[derive(Clone, Debug)]
+pub struct SplashMusic {
+    pub path: String,
+    pub volume: f32,
+    /// Fade out when the splash ends or is skipped
+    pub fade_out: Duration,
+    /// Keep playing on the next state, find it with [`SplashMusicTrack`]
+    pub keep: bool,
+}
+
+impl SplashMusic {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            volume: 1.,
+            fade_out: Duration::from_secs(1),
+            keep: false,
+        }
+    }
+}
+
+/// Entity of the [`SplashMusic`] playing
+#[derive(Component)]
+pub struct SplashMusicTrack;
+
+// Sound to play when the brand fade in
+#[derive(Component)]
+pub(crate) struct SplashAudioCue {
+    source: Handle<AudioSource>,
+    volume: f32,
+    fade_out: Duration,
+    start: Duration,
+    elapsed: Duration,
+    // brand fade in and fade out completed
+    completed: u32,
+    playing: Option<Entity>,
+}
+
+impl SplashAudioCue {
+    pub(crate) fn new(audio: &SplashAudio, assets: &AssetServer, fade_in: Duration) -> Self {
+        Self {
+            source: assets.load(&audio.path),
+            volume: audio.volume,
+            fade_out: audio.fade_out,
+            start: fade_in + audio.offset,
+            elapsed: Duration::ZERO,
+            completed: 0,
+            playing: None,
+        }
+    }
+}
+
+// Splash sound, fade out and despawn when the owner is despawned
+#[derive(Component)]
+pub(crate) struct SplashAudioFade {
+    pub(crate) owner: Entity,
+    pub(crate) volume: f32,
+    pub(crate) duration: Duration,
+    pub(crate) elapsed: Option<Duration>,
+    // Only unlink from the owner, do not fade
+    pub(crate) keep: bool,
+}
+
+pub(crate) fn splash_audio_bundle(source: Handle<AudioSource>, volume: f32) -> AudioBundle {
+    AudioBundle {
+        source,
+        settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(volume)),
+    }
+}
+
+//
+// Play brand sounds and fade them when the brand ends
+//
+pub(crate) fn brand_audio(
+    mut cmd: Commands,
+    time: Res<Time>,
+    mut reader: EventReader<TweenCompleted>,
+    mut cues: Query<(Entity, &mut SplashAudioCue)>,
+    mut sounds: Query<&mut SplashAudioFade>,
+) {
+    for e in reader.read() {
+        if let Ok((_, mut cue)) = cues.get_mut(e.entity) {
+            cue.completed += 1;
+        }
+    }
+
+    for (brand, mut cue) in cues.iter_mut() {
+        cue.elapsed += time.delta();
+
+        if cue.completed >= 2 {
+            if let Some(mut fade) = cue.playing.take().and_then(|e| sounds.get_mut(e).ok()) {
+                fade.elapsed.get_or_insert(Duration::ZERO);
+            }
+        } else if cue.playing.is_none() && cue.elapsed >= cue.start {
+            let sound = cmd
+                .spawn(splash_audio_bundle(cue.source.clone(), cue.volume))
+                .insert(SplashAudioFade {
+                    owner: brand,
+                    volume: cue.volume,
+                    duration: cue.fade_out,
+                    elapsed: None,
+                    keep: false,
+                })
+                .id();
+            cue.playing = Some(sound);
+        }
+    }
+}
+
+//
+// Start music of new sequences
+//
+pub(crate) fn start_music(
+    mut cmd: Commands,
+    assets: Res<AssetServer>,
+    sequences: Query<(Entity, &SplashSequence), Added<SplashSequence>>,
+) {
+    for (root, sequence) in sequences.iter() {
+        let Some(music) = &sequence.music else {
+            continue;
+        };
+        cmd.spawn(AudioBundle {
+            source: assets.load(&music.path),
+            settings: PlaybackSettings::LOOP.with_volume(Volume::new(music.volume)),
+        })
+        .insert(SplashMusicTrack)
+        .insert(SplashAudioFade {
+            owner: root,
+            volume: music.volume,
+            duration: music.fade_out,
+            elapsed: None,
+            keep: music.keep,
+        });
+    }
+}
+
+//
+// Fade out sounds of ended brands or splash
+//
+pub(crate) fn fade_audio(
+    mut cmd: Commands,
+    time: Res<Time>,
+    entities: &Entities,
+    mut sounds: Query<(Entity, &mut SplashAudioFade, Option<&AudioSink>)>,
+) {
+    for (entity, mut fade, sink) in sounds.iter_mut() {
+        if fade.elapsed.is_none() && !entities.contains(fade.owner) {
+            if fade.keep {
+                cmd.entity(entity).remove::<SplashAudioFade>();
+                continue;
+            }
+            fade.elapsed = Some(Duration::ZERO);
+        }
+        let Some(elapsed) = fade.elapsed.as_mut() else {
+            continue;
+        };
+        *elapsed += time.delta();
+
+        let ratio = 1. - (elapsed.as_secs_f32() / fade.duration.as_secs_f32().max(f32::EPSILON));
+        if let Some(sink) = sink {
+            sink.set_volume(fade.volume * ratio.max(0.));
+        }
+        if ratio <= 0. {
+            cmd.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use bevy::prelude::*;
+#[cfg(feature = "ui")]
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::SplashScreen;
+#[cfg(feature = "ui")]
+use crate::{ColorLerper as _, SplashMaterialBackground};
+
+#[cfg(feature = "ui")]
+const GRADIENT_SIZE: u32 = 128;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplashImageFit {
+    /// Fill the area ignoring the aspect ratio
+    #[default]
+    Stretch,
+    /// All the image visible, keeping the aspect ratio
+    Contain,
+    /// Fill the area keeping the aspect ratio, the image is cropped
+    Cover,
+    /// Repeat the image with its own size
+    Tile,
+    /// Size of the image in pixels
+    Native,
+    /// Width of the area, the height keeps the aspect ratio
+    FitWidth,
+}
+
+#[cfg(feature = "ui")]
+impl SplashImageFit {
+    // Size of the image inside the area, `None` to use all the area
+    pub(crate) fn size(&self, image: Vec2, area: Vec2) -> Option<Vec2> {
+        let scale = match self {
+            SplashImageFit::Stretch | SplashImageFit::Tile => return None,
+            SplashImageFit::Contain => (area / image).min_element(),
+            SplashImageFit::Cover => (area / image).max_element(),
+            SplashImageFit::Native => 1.,
+            SplashImageFit::FitWidth => area.x / image.x,
+        };
+        Some(image * scale)
+    }
+
+    // Brands need a clipping node with the size of the brand
+    pub(crate) fn clipped(&self) -> bool {
+        matches!(
+            self,
+            SplashImageFit::Contain | SplashImageFit::Cover | SplashImageFit::Tile
+        )
+    }
+}
+
+/// Background of a screen, drawn over the `background_color`.
+/// World splashes only use the colors
+#[derive(Clone, Debug)]
+pub enum SplashBackgroundType {
+    Color(Color),
+    Image(String, SplashImageFit),
+    /// Angle in radians, `0` goes from left to right
+    LinearGradient {
+        start: Color,
+        end: Color,
+        angle: f32,
+    },
+    RadialGradient {
+        center: Color,
+        edge: Color,
+    },
+    /// Needs [`SplashMaterialPlugin`](crate::SplashMaterialPlugin) of the material
+    #[cfg(feature = "ui")]
+    Material(SplashMaterialBackground),
+}
+
+// Color of the root node while the screen is shown
+pub(crate) fn screen_color(screen: &SplashScreen) -> Color {
+    match screen.background {
+        Some(SplashBackgroundType::Color(color)) => color,
+        _ => screen.background_color.0,
+    }
+}
+
+#[cfg(feature = "ui")]
+impl SplashBackgroundType {
+    // Image to draw over the background color, gradients are baked into an image
+    pub(crate) fn image(
+        &self,
+        assets: &AssetServer,
+        images: &mut Assets<Image>,
+    ) -> Option<(Handle<Image>, SplashImageFit)> {
+        let gradient = match self {
+            SplashBackgroundType::Color(_) | SplashBackgroundType::Material(_) => return None,
+            SplashBackgroundType::Image(path, fit) => return Some((assets.load(path), *fit)),
+            SplashBackgroundType::LinearGradient { start, end, angle } => {
+                let dir = Vec2::from_angle(*angle);
+                // project the corners to normalize the gradient
+                let len = dir.x.abs() + dir.y.abs();
+                gradient_image(|uv| {
+                    let t = (uv - 0.5).dot(dir) / len + 0.5;
+                    start.lerp(end, t.clamp(0., 1.))
+                })
+            }
+            SplashBackgroundType::RadialGradient { center, edge } => gradient_image(|uv| {
+                let t = (uv - 0.5).length() / 0.5_f32.hypot(0.5);
+                center.lerp(edge, t.clamp(0., 1.))
+            }),
+        };
+        Some((images.add(gradient), SplashImageFit::Stretch))
+    }
+}
+
+#[cfg(feature = "ui")]
+fn gradient_image(color: impl Fn(Vec2) -> Color) -> Image {
+    let data = (0..GRADIENT_SIZE * GRADIENT_SIZE)
+        .flat_map(|i| {
+            let uv = Vec2::new(
+                (i % GRADIENT_SIZE) as f32 / (GRADIENT_SIZE - 1) as f32,
+                // image rows go from top to bottom
+                1. - (i / GRADIENT_SIZE) as f32 / (GRADIENT_SIZE - 1) as f32,
+            );
+            color(uv).to_srgba().to_u8_array()
+        })
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: GRADIENT_SIZE,
+            height: GRADIENT_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+// Image node sized by `fit_images` from its parent size
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct SplashImageFitted(pub(crate) SplashImageFit);
+
+// Box of a clipped brand image, its sizes left to auto come from the image
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct SplashImageBox(pub(crate) Handle<Image>);
+
+//
+// Show the background image of a screen, or hide it if has not one
+//
+#[cfg(feature = "ui")]
+pub(crate) fn set_background_image(
+    cmd: &mut Commands,
+    entity: Entity,
+    image: Option<&(Handle<Image>, SplashImageFit)>,
+) {
+    let mut entity = cmd.entity(entity);
+    entity.remove::<ImageScaleMode>();
+    let Some((texture, fit)) = image else {
+        entity.insert(Visibility::Hidden);
+        return;
+    };
+
+    entity.insert((
+        UiImage::new(texture.clone()),
+        SplashImageFitted(*fit),
+        Visibility::Inherited,
+    ));
+    if *fit == SplashImageFit::Tile {
+        entity.insert(ImageScaleMode::Tiled {
+            tile_x: true,
+            tile_y: true,
+            stretch_value: 1.,
+        });
+    }
+}
+
+//
+// Keep the aspect ratio of fitted images centered on their parent
+//
+#[cfg(feature = "ui")]
+pub(crate) fn fit_images(
+    images: Res<Assets<Image>>,
+    nodes: Query<&Node>,
+    mut fitted: Query<(&Parent, &UiImage, &SplashImageFitted, &mut Style)>,
+) {
+    for (parent, image, fit, mut style) in fitted.iter_mut() {
+        let (Some(image), Ok(parent)) = (images.get(&image.texture), nodes.get(parent.get()))
+        else {
+            continue;
+        };
+        let area = parent.size();
+
+        let (width, height, left, top) = match fit.0.size(image.size_f32(), area) {
+            Some(size) => (
+                Val::Px(size.x),
+                Val::Px(size.y),
+                Val::Px((area.x - size.x) / 2.),
+                Val::Px((area.y - size.y) / 2.),
+            ),
+            None => (Val::Percent(100.), Val::Percent(100.), Val::ZERO, Val::ZERO),
+        };
+        if style.width != width || style.height != height || style.left != left || style.top != top
+        {
+            style.width = width;
+            style.height = height;
+            style.left = left;
+            style.top = top;
+        }
+    }
+}
+
+//
+// Size the boxes of clipped images, they have no content to be measured by
+//
+#[cfg(feature = "ui")]
+pub(crate) fn size_image_boxes(
+    images: Res<Assets<Image>>,
+    mut boxes: Query<(&SplashImageBox, &mut Style)>,
+) {
+    for (image_box, mut style) in boxes.iter_mut() {
+        let Some(image) = images.get(&image_box.0) else {
+            continue;
+        };
+        let size = image.size_f32();
+        match (style.width, style.height) {
+            (Val::Auto, Val::Auto) => {
+                style.width = Val::Px(size.x);
+                style.height = Val::Px(size.y);
+            }
+            // The other size follows the aspect ratio of the image
+            (Val::Auto, _) | (_, Val::Auto) if style.aspect_ratio.is_none() => {
+                style.aspect_ratio = Some(size.x / size.y.max(1.));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ui"))]
+mod tests {
+    use super::*;
+
+    const IMAGE: Vec2 = Vec2::new(200., 100.);
+
+    #[test]
+    fn size_fills_area() {
+        let area = Vec2::new(400., 400.);
+        assert_eq!(SplashImageFit::Stretch.size(IMAGE, area), None);
+        assert_eq!(SplashImageFit::Tile.size(IMAGE, area), None);
+    }
+
+    #[test]
+    fn size_keeps_aspect_ratio() {
+        let area = Vec2::new(400., 400.);
+        assert_eq!(
+            SplashImageFit::Contain.size(IMAGE, area),
+            Some(Vec2::new(400., 200.))
+        );
+        assert_eq!(
+            SplashImageFit::Cover.size(IMAGE, area),
+            Some(Vec2::new(800., 400.))
+        );
+        assert_eq!(
+            SplashImageFit::FitWidth.size(IMAGE, Vec2::new(100., 400.)),
+            Some(Vec2::new(100., 50.))
+        );
+    }
+
+    #[test]
+    fn size_native() {
+        assert_eq!(
+            SplashImageFit::Native.size(IMAGE, Vec2::new(50., 50.)),
+            Some(IMAGE)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::window::WindowRef;
+
+use crate::{background::screen_color, systems::SplashSequence};
+
+/// Camera rendering the splash
+#[derive(Clone, Copy, Debug, Default)]
+pub enum SplashCamera {
+    /// Camera picked by `bevy_ui`
+    #[default]
+    Default,
+    /// Render on this camera, it is not despawned
+    Target(Entity),
+    /// Spawn a UI camera on the window, despawned when the splash ends.
+    /// Without `clear_color` clears with the background of the first screen
+    Spawn {
+        window: WindowRef,
+        clear_color: Option<Color>,
+    },
+}
+
+impl SplashCamera {
+    /// Own camera on the primary window
+    pub fn spawn() -> Self {
+        Self::Spawn {
+            window: WindowRef::Primary,
+            clear_color: None,
+        }
+    }
+}
+
+//
+// Set the camera of new sequences
+//
+#[cfg_attr(not(feature = "ui"), allow(unused_variables))]
+pub(crate) fn spawn_camera(
+    mut cmd: Commands,
+    mut sequences: Query<(Entity, &mut SplashSequence), Added<SplashSequence>>,
+) {
+    for (root, mut sequence) in sequences.iter_mut() {
+        let camera = match sequence.camera {
+            SplashCamera::Default => continue,
+            SplashCamera::Target(camera) => camera,
+            SplashCamera::Spawn {
+                window,
+                clear_color,
+            } => {
+                let clear_color = clear_color
+                    .or(sequence.screens.0.first().map(screen_color))
+                    .map_or(ClearColorConfig::Default, ClearColorConfig::Custom);
+                let camera = cmd
+                    .spawn(Camera2dBundle {
+                        camera: Camera {
+                            target: RenderTarget::Window(window),
+                            // Over the cameras of the app
+                            order: 1,
+                            clear_color,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .id();
+                sequence.owned_camera = Some(camera);
+                camera
+            }
+        };
+        // World splashes are drawn by every camera seeing the sprites
+        #[cfg(feature = "ui")]
+        cmd.entity(root).insert(TargetCamera(camera));
+    }
+}
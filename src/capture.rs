@@ -0,0 +1,432 @@
+use std::error::Error;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy::asset::{LoadState, UntypedAssetId};
+use bevy::prelude::*;
+use bevy::render::{
+    camera::RenderTarget,
+    graph::CameraDriverLabel,
+    render_asset::{RenderAssetUsages, RenderAssets},
+    render_graph::{self, NodeRunError, RenderGraph, RenderGraphContext, RenderLabel},
+    render_resource::{
+        Buffer, BufferAsyncError, BufferDescriptor, BufferUsages, Extent3d, ImageCopyBuffer,
+        ImageDataLayout, Maintain, MapMode, TextureDimension, TextureFormat, TextureUsages,
+    },
+    renderer::{RenderContext, RenderDevice},
+    texture::{BevyDefault, GpuImage},
+    Extract, Render, RenderApp, RenderSet,
+};
+
+use crate::{
+    background::screen_color,
+    camera::spawn_camera,
+    condition::filter_splash,
+    systems::{update_splash, SplashBackground, SplashSequence},
+    SplashCamera,
+};
+
+/// Render the splash offscreen and write every frame as a PNG, for store page
+/// GIFs and press kit stills. Each frame advances the timeline by `1 / fps`
+/// and it waits for the brands to load, so the frames are the same on every run.
+/// The capture holds the virtual time while a frame is read back, its paused
+/// state is restored at the end
+#[derive(Clone, Debug)]
+pub struct SplashCapture {
+    /// Folder of the frames, named `00000.png`, `00001.png`...
+    pub directory: PathBuf,
+    /// Size of the frames in pixels, the splash is laid out on it
+    pub size: UVec2,
+    pub fps: u32,
+}
+
+impl SplashCapture {
+    pub fn new(directory: impl Into<PathBuf>, size: UVec2) -> Self {
+        Self {
+            directory: directory.into(),
+            size,
+            fps: 60,
+        }
+    }
+
+    pub fn with_fps(mut self, fps: u32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    fn step(&self) -> Duration {
+        Duration::from_secs(1) / self.fps.max(1)
+    }
+}
+
+// Camera rendering a capture, also extracted to copy its frames
+#[derive(Component, Clone)]
+pub(crate) struct SplashCaptureCamera {
+    // Sequence shown on the frames
+    root: Entity,
+    directory: PathBuf,
+    image: Handle<Image>,
+    buffer: Buffer,
+    size: UVec2,
+    // Frame rendered now, `None` until the splash is built, while it loads or a frame is read
+    frame: Option<u32>,
+    next: u32,
+    // Frame read back from the gpu, the next one waits for it to be saved
+    reading: Option<u32>,
+}
+
+// Frame copied from the gpu, rows are padded to the copy alignment
+struct SplashFrame {
+    camera: Entity,
+    directory: PathBuf,
+    frame: u32,
+    size: UVec2,
+    data: Result<Vec<u8>, Box<dyn Error + Send + Sync>>,
+}
+
+impl SplashFrame {
+    fn save(self) -> Result<(), Box<dyn Error>> {
+        std::fs::create_dir_all(&self.directory)?;
+        let row = self.size.x as usize * 4;
+        let data = self
+            .data
+            .map_err(|e| e as Box<dyn Error>)?
+            .chunks(padded_row(self.size.x))
+            .flat_map(|padded| &padded[..row])
+            .copied()
+            .collect();
+        let image = Image::new(
+            extent(self.size),
+            TextureDimension::D2,
+            data,
+            TextureFormat::bevy_default(),
+            RenderAssetUsages::MAIN_WORLD,
+        );
+        image
+            .try_into_dynamic()?
+            .to_rgba8()
+            .save(self.directory.join(format!("{:05}.png", self.frame)))?;
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct SplashFrameReceiver(Mutex<Receiver<SplashFrame>>);
+
+#[derive(Resource)]
+struct SplashFrameSender(Sender<SplashFrame>);
+
+// Buffers mapped by `map_async`, read on a later frame
+type SplashMapped = (Entity, SplashCaptureCamera, Result<(), BufferAsyncError>);
+
+#[derive(Resource)]
+struct SplashMappedChannel(Sender<SplashMapped>, Mutex<Receiver<SplashMapped>>);
+
+// Captures with a frame to copy, in the render world
+#[derive(Resource, Default)]
+struct SplashCaptures(Vec<(Entity, SplashCaptureCamera)>);
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, RenderLabel)]
+struct SplashCaptureLabel;
+
+// Copy the rendered captures into their buffers
+#[derive(Default)]
+struct SplashCaptureNode;
+
+fn extent(size: UVec2) -> Extent3d {
+    Extent3d {
+        width: size.x,
+        height: size.y,
+        depth_or_array_layers: 1,
+    }
+}
+
+fn padded_row(width: u32) -> usize {
+    RenderDevice::align_copy_bytes_per_row(width as usize * 4)
+}
+
+pub(crate) fn build_capture(app: &mut App) {
+    let (sender, receiver) = mpsc::channel();
+    let (mapped_sender, mapped_receiver) = mpsc::channel();
+    let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+        return;
+    };
+    render_app
+        .insert_resource(SplashFrameSender(sender))
+        .insert_resource(SplashMappedChannel(
+            mapped_sender,
+            Mutex::new(mapped_receiver),
+        ))
+        .init_resource::<SplashCaptures>()
+        .add_systems(ExtractSchedule, extract_captures)
+        .add_systems(Render, send_frames.after(RenderSet::Render));
+    let mut graph = render_app.world_mut().resource_mut::<RenderGraph>();
+    graph.add_node(SplashCaptureLabel, SplashCaptureNode);
+    graph.add_node_edge(CameraDriverLabel, SplashCaptureLabel);
+
+    app.insert_resource(SplashFrameReceiver(Mutex::new(receiver)))
+        .add_systems(
+            Update,
+            (
+                spawn_capture.after(filter_splash).before(spawn_camera),
+                save_frames,
+                capture_frames.after(update_splash).after(save_frames),
+            ),
+        );
+}
+
+//
+// Render new sequences with a capture into an image
+//
+fn spawn_capture(
+    mut cmd: Commands,
+    device: Res<RenderDevice>,
+    mut images: ResMut<Assets<Image>>,
+    mut sequences: Query<(Entity, &mut SplashSequence), Added<SplashSequence>>,
+) {
+    for (root, mut sequence) in sequences.iter_mut() {
+        let Some(capture) = sequence.capture.clone() else {
+            continue;
+        };
+        let mut image = Image::new_fill(
+            extent(capture.size),
+            TextureDimension::D2,
+            &[0; 4],
+            TextureFormat::bevy_default(),
+            RenderAssetUsages::default(),
+        );
+        image.texture_descriptor.usage |= TextureUsages::COPY_SRC
+            | TextureUsages::RENDER_ATTACHMENT
+            | TextureUsages::TEXTURE_BINDING;
+        let image = images.add(image);
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("splash_capture_buffer"),
+            size: (padded_row(capture.size.x) * capture.size.y as usize) as u64,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let clear_color = sequence
+            .screens
+            .0
+            .first()
+            .map_or(ClearColorConfig::Default, |screen| {
+                ClearColorConfig::Custom(screen_color(screen))
+            });
+
+        sequence.fixed_step = Some(capture.step());
+        let camera = cmd
+            .spawn((
+                Camera2dBundle {
+                    camera: Camera {
+                        target: RenderTarget::Image(image.clone()),
+                        clear_color,
+                        ..default()
+                    },
+                    ..default()
+                },
+                SplashCaptureCamera {
+                    root,
+                    directory: capture.directory,
+                    image,
+                    buffer,
+                    size: capture.size,
+                    frame: None,
+                    next: 0,
+                    reading: None,
+                },
+            ))
+            .id();
+        // Picked by `spawn_camera`, despawned with the splash
+        sequence.camera = SplashCamera::Target(camera);
+        sequence.owned_camera = Some(camera);
+    }
+}
+
+//
+// Number the frames once the splash is built and its brands are loaded, one per
+// step of the timeline, holding it while loading or reading a frame
+//
+fn capture_frames(
+    assets: Res<AssetServer>,
+    mut time: ResMut<Time<Virtual>>,
+    mut captures: Query<&mut SplashCaptureCamera>,
+    built: Query<(), With<SplashBackground>>,
+    children: Query<&Children>,
+    #[cfg(feature = "ui")] images: Query<&UiImage>,
+    textures: Query<&Handle<Image>>,
+    texts: Query<&Text>,
+    mut paused: Local<Option<bool>>,
+) {
+    if captures.is_empty() {
+        // Back to the time of the app before the capture
+        match paused.take() {
+            Some(true) => time.pause(),
+            Some(false) => time.unpause(),
+            None => {}
+        }
+        return;
+    }
+    paused.get_or_insert(time.is_paused());
+
+    let loading_id =
+        |id: UntypedAssetId| matches!(assets.get_load_state(id), Some(LoadState::Loading));
+    // Only the images and fonts of the captured sequence
+    let loading = |root: Entity| {
+        children.iter_descendants(root).any(|entity| {
+            #[cfg(feature = "ui")]
+            if let Ok(image) = images.get(entity) {
+                if loading_id(image.texture.id().untyped()) {
+                    return true;
+                }
+            }
+            textures
+                .get(entity)
+                .is_ok_and(|t| loading_id(t.id().untyped()))
+                || texts.get(entity).is_ok_and(|t| {
+                    t.sections
+                        .iter()
+                        .any(|s| loading_id(s.style.font.id().untyped()))
+                })
+        })
+    };
+    let reading = captures.iter().any(|c| c.reading.is_some());
+    let mut hold = reading;
+
+    for mut capture in captures.iter_mut() {
+        // Numbered from the first frame showing the splash
+        let ready = built.contains(capture.root) && !loading(capture.root);
+        hold |= built.contains(capture.root) && !ready;
+        // The time held since the last frame did not move, but before the first one
+        let take = ready && !reading && (!time.is_paused() || capture.next == 0);
+        capture.frame = take.then_some(capture.next);
+        if take {
+            capture.reading = Some(capture.next);
+            capture.next += 1;
+        }
+    }
+    if hold || captures.iter().any(|c| c.reading.is_some()) {
+        time.pause();
+    } else {
+        time.unpause();
+    }
+}
+
+//
+// Write the frames copied by the render world
+//
+fn save_frames(receiver: Res<SplashFrameReceiver>, mut captures: Query<&mut SplashCaptureCamera>) {
+    let Ok(receiver) = receiver.0.lock() else {
+        return;
+    };
+    for frame in receiver.try_iter() {
+        let (camera, directory, number) = (frame.camera, frame.directory.clone(), frame.frame);
+        if let Err(e) = frame.save() {
+            error!("Failed to save splash frame {number} to {directory:?}: {e}");
+        }
+        if let Ok(mut capture) = captures.get_mut(camera) {
+            if capture.reading == Some(number) {
+                capture.reading = None;
+            }
+        }
+    }
+}
+
+fn extract_captures(mut cmd: Commands, captures: Extract<Query<(Entity, &SplashCaptureCamera)>>) {
+    cmd.insert_resource(SplashCaptures(
+        captures
+            .iter()
+            .filter(|(_, c)| c.frame.is_some())
+            .map(|(entity, c)| (entity, c.clone()))
+            .collect(),
+    ));
+}
+
+impl render_graph::Node for SplashCaptureNode {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let captures = world.resource::<SplashCaptures>();
+        let gpu_images = world.resource::<RenderAssets<GpuImage>>();
+
+        for (_, capture) in &captures.0 {
+            let Some(image) = gpu_images.get(&capture.image) else {
+                continue;
+            };
+            // Recorded after the cameras of the graph
+            render_context.command_encoder().copy_texture_to_buffer(
+                image.texture.as_image_copy(),
+                ImageCopyBuffer {
+                    buffer: &capture.buffer,
+                    layout: ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(padded_row(image.size.x) as u32),
+                        rows_per_image: None,
+                    },
+                },
+                extent(image.size),
+            );
+        }
+        Ok(())
+    }
+}
+
+//
+// Map the copied frames and send the mapped ones to the main world
+//
+fn send_frames(
+    captures: Res<SplashCaptures>,
+    gpu_images: Res<RenderAssets<GpuImage>>,
+    device: Res<RenderDevice>,
+    mapped: Res<SplashMappedChannel>,
+    sender: Res<SplashFrameSender>,
+) {
+    for (camera, capture) in &captures.0 {
+        let frame = |data| SplashFrame {
+            camera: *camera,
+            directory: capture.directory.clone(),
+            frame: capture.frame.unwrap_or_default(),
+            size: capture.size,
+            data,
+        };
+        if gpu_images.get(&capture.image).is_none() {
+            // Not copied, the main world stops waiting for it
+            let _ = sender
+                .0
+                .send(frame(Err("the frame was not rendered".into())));
+            continue;
+        }
+        let (camera, mapped_capture, mapped_sender) = (*camera, capture.clone(), mapped.0.clone());
+        capture
+            .buffer
+            .slice(..)
+            .map_async(MapMode::Read, move |result| {
+                let _ = mapped_sender.send((camera, mapped_capture, result));
+            });
+    }
+    // Calls `map_async` of the frames done by the gpu
+    device.poll(Maintain::Poll);
+
+    let Ok(receiver) = mapped.1.lock() else {
+        return;
+    };
+    for (camera, capture, result) in receiver.try_iter() {
+        let data = result.map_err(Into::into).map(|()| {
+            let data = capture.buffer.slice(..).get_mapped_range().to_vec();
+            capture.buffer.unmap();
+            data
+        });
+        // The receiver is gone when the app exits
+        let _ = sender.0.send(SplashFrame {
+            camera,
+            directory: capture.directory,
+            frame: capture.frame.unwrap_or_default(),
+            size: capture.size,
+            data,
+        });
+    }
+}
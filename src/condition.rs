@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+use crate::{
+    splash::get_screen_delays,
+    systems::{SplashBackground, SplashSequence},
+    SplashScreen,
+};
+
+/// Persist which screens were already shown between runs
+pub trait SplashStorage: Send + Sync + 'static {
+    fn load(&self, key: &str) -> Option<String>;
+    fn save(&mut self, key: &str, value: &str);
+}
+
+/// File storage, one `key=value` per line
+pub struct SplashFileStorage {
+    path: PathBuf,
+    values: HashMap<String, String>,
+}
+
+impl SplashFileStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let values = std::fs::read_to_string(&path)
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| line.split_once('='))
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { path, values }
+    }
+
+    /// `splash_screen.seen` in the data folder of `app` for the current user:
+    /// `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and
+    /// `$XDG_DATA_HOME` or `~/.local/share` elsewhere
+    pub fn user_data(app: &str) -> Option<Self> {
+        let home = || std::env::var_os("HOME").map(PathBuf::from);
+        let data = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            home().map(|home| home.join("Library/Application Support"))
+        } else {
+            std::env::var_os("XDG_DATA_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".local/share")))
+        }?;
+        Some(Self::new(data.join(app).join("splash_screen.seen")))
+    }
+}
+
+impl SplashStorage for SplashFileStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        self.values.get(key).cloned()
+    }
+
+    fn save(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+        let content = self
+            .values
+            .iter()
+            .map(|(k, v)| format!("{k}={v}\n"))
+            .collect::<String>();
+        let written = match self.path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => std::fs::create_dir_all(dir),
+            _ => Ok(()),
+        }
+        .and_then(|_| std::fs::write(&self.path, content));
+        if let Err(e) = written {
+            warn!("Cannot save splash storage {:?}: {e}", self.path);
+        }
+    }
+}
+
+/// Storage used by [`SplashShow`], without it the screens are always shown.
+/// Insert it with a writable path, like [`SplashFileStorage::user_data`]
+#[derive(Resource)]
+pub struct SplashSeen(pub Box<dyn SplashStorage>);
+
+impl SplashSeen {
+    pub fn new(storage: impl SplashStorage) -> Self {
+        Self(Box::new(storage))
+    }
+}
+
+/// Run condition evaluated when the sequence starts
+#[derive(Clone)]
+pub struct SplashCondition(Arc<dyn Fn(&mut World) -> bool + Send + Sync>);
+
+impl SplashCondition {
+    /// Predicate on the world, e.g. check a build or region resource
+    pub fn new(predicate: impl Fn(&World) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(move |world| predicate(world)))
+    }
+
+    pub fn run_if<M>(
+        condition: impl IntoSystem<(), bool, M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(move |world| {
+            world.run_system_once(condition.clone())
+        }))
+    }
+
+    pub(crate) fn check(&self, world: &mut World) -> bool {
+        (self.0)(world)
+    }
+}
+
+#[derive(Clone, Default)]
+pub enum SplashShow {
+    #[default]
+    Always,
+    /// Until it is fully shown or skipped once started, `key` identifies the
+    /// screen on [`SplashSeen`]
+    FirstLaunch(String),
+    /// Key and version, e.g. `env!("CARGO_PKG_VERSION")`
+    OncePerVersion(String, String),
+    If(SplashCondition),
+}
+
+impl SplashShow {
+    // Key and value stored once shown
+    fn seen_value(&self) -> Option<(&str, &str)> {
+        match self {
+            SplashShow::Always | SplashShow::If(_) => None,
+            SplashShow::FirstLaunch(key) => Some((key, "1")),
+            SplashShow::OncePerVersion(key, version) => Some((key, version)),
+        }
+    }
+
+    fn check(&self, world: &mut World) -> bool {
+        if let SplashShow::If(condition) = self {
+            return condition.check(world);
+        }
+        let Some((key, value)) = self.seen_value() else {
+            return true;
+        };
+        let Some(seen) = world.get_resource::<SplashSeen>() else {
+            warn_once!("Splash shown once without `SplashSeen`, it is shown on every launch");
+            return true;
+        };
+        seen.0.load(key).as_deref() != Some(value)
+    }
+
+    fn mark_seen(&self, seen: &mut SplashSeen) {
+        if let Some((key, value)) = self.seen_value() {
+            seen.0.save(key, value);
+        }
+    }
+}
+
+// Store the screen and its brands as shown, once its brands end
+pub(crate) fn mark_screen_seen(screen: &SplashScreen, seen: &mut SplashSeen) {
+    screen.show.mark_seen(seen);
+    for brand in &screen.brands {
+        brand.show.mark_seen(seen);
+    }
+}
+
+// Store the screens already started as shown, when the sequence is skipped
+pub(crate) fn mark_started_seen(sequence: &SplashSequence, seen: &mut SplashSeen) {
+    let delays = get_screen_delays(&sequence.screens);
+    for (screen, delay) in sequence.screens.0.iter().zip(delays) {
+        if sequence.elapsed >= Duration::from_secs(1) + delay {
+            mark_screen_seen(screen, seen);
+        }
+    }
+}
+
+//
+// Remove screens and brands which should not be shown before create the splash
+//
+pub(crate) fn filter_splash(world: &mut World) {
+    let sequences = world
+        .query_filtered::<Entity, (With<SplashSequence>, Without<SplashBackground>)>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for entity in sequences {
+        let screens =
+            std::mem::take(&mut world.get_mut::<SplashSequence>(entity).unwrap().screens.0);
+        let screens = screens
+            .into_iter()
+            .filter_map(|mut screen| {
+                if !screen.show.check(world) {
+                    return None;
+                }
+                screen.brands.retain(|brand| brand.show.check(world));
+                (!screen.brands.is_empty()).then_some(screen)
+            })
+            .collect();
+        world.get_mut::<SplashSequence>(entity).unwrap().screens.0 = screens;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlaySplash, SplashItem};
+
+    #[derive(Default)]
+    struct MemoryStorage(HashMap<String, String>);
+
+    impl SplashStorage for MemoryStorage {
+        fn load(&self, key: &str) -> Option<String> {
+            self.0.get(key).cloned()
+        }
+
+        fn save(&mut self, key: &str, value: &str) {
+            self.0.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    fn world(seen: &[(&str, &str)]) -> World {
+        let mut storage = MemoryStorage::default();
+        for (key, value) in seen {
+            storage.save(key, value);
+        }
+        let mut world = World::new();
+        world.insert_resource(SplashSeen::new(storage));
+        world
+    }
+
+    fn stored(world: &World, key: &str) -> Option<String> {
+        world.resource::<SplashSeen>().0.load(key)
+    }
+
+    fn first_launch(key: &str) -> SplashShow {
+        SplashShow::FirstLaunch(key.to_string())
+    }
+
+    fn screen(show: SplashShow, brands: Vec<SplashShow>) -> SplashScreen {
+        SplashScreen {
+            show,
+            brands: brands
+                .into_iter()
+                .map(|show| SplashItem { show, ..default() })
+                .collect(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn check_first_launch() {
+        let mut world = world(&[("intro", "1")]);
+        assert!(!first_launch("intro").check(&mut world));
+        assert!(first_launch("logo").check(&mut world));
+        assert!(SplashShow::Always.check(&mut world));
+    }
+
+    #[test]
+    fn check_once_per_version() {
+        let mut world = world(&[("intro", "0.6.0")]);
+        let version = |v: &str| SplashShow::OncePerVersion("intro".to_string(), v.to_string());
+        assert!(!version("0.6.0").check(&mut world));
+        assert!(version("0.7.0").check(&mut world));
+    }
+
+    #[test]
+    fn check_without_storage() {
+        assert!(first_launch("intro").check(&mut World::new()));
+    }
+
+    #[test]
+    fn check_condition() {
+        #[derive(Resource)]
+        struct Demo;
+
+        let show = SplashShow::If(SplashCondition::new(|world| {
+            world.contains_resource::<Demo>()
+        }));
+        let mut world = World::new();
+        assert!(!show.check(&mut world));
+        world.insert_resource(Demo);
+        assert!(show.check(&mut world));
+    }
+
+    #[test]
+    fn mark_screen_and_brands() {
+        let mut world = world(&[]);
+        let screen = screen(
+            SplashShow::OncePerVersion("intro".to_string(), "0.6.0".to_string()),
+            vec![first_launch("logo"), SplashShow::Always],
+        );
+        mark_screen_seen(&screen, &mut world.resource_mut::<SplashSeen>());
+        assert_eq!(stored(&world, "intro").as_deref(), Some("0.6.0"));
+        assert_eq!(stored(&world, "logo").as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn filter_seen() {
+        let mut world = world(&[("seen", "1")]);
+        let sequence = world
+            .spawn(SplashSequence::from(PlaySplash {
+                screens: vec![
+                    screen(
+                        SplashShow::Always,
+                        vec![SplashShow::Always, first_launch("seen")],
+                    ),
+                    screen(first_launch("seen"), vec![SplashShow::Always]),
+                    screen(SplashShow::Always, vec![first_launch("seen")]),
+                    screen(first_launch("new"), vec![SplashShow::Always]),
+                ],
+                ..default()
+            }))
+            .id();
+        filter_splash(&mut world);
+
+        let screens = &world.get::<SplashSequence>(sequence).unwrap().screens.0;
+        assert_eq!(screens.len(), 2);
+        assert_eq!(screens[0].brands.len(), 1);
+        assert!(matches!(&screens[1].show, SplashShow::FirstLaunch(key) if key == "new"));
+        // Stored once shown, not when filtered
+        assert_eq!(stored(&world, "new"), None);
+    }
+
+    #[test]
+    fn skip_marks_started_screens() {
+        let mut world = world(&[]);
+        let mut sequence = SplashSequence::from(PlaySplash {
+            screens: vec![
+                screen(first_launch("first"), vec![SplashShow::Always]),
+                screen(first_launch("second"), vec![SplashShow::Always]),
+            ],
+            ..default()
+        });
+        sequence.elapsed = Duration::from_secs(2);
+        mark_started_seen(&sequence, &mut world.resource_mut::<SplashSeen>());
+        assert_eq!(stored(&world, "first").as_deref(), Some("1"));
+        assert_eq!(stored(&world, "second"), None);
+    }
+}
@@ -14,28 +14,106 @@ pub struct SplashImageColorLens {
     pub end: Color,
 }
 
+/// Glyphs fading at the same time on [`SplashTextAnimation::Wave`]
+const WAVE_WIDTH: f32 = 4.;
+
+/// How the sections of a text brand are revealed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplashTextAnimation {
+    /// All the sections fade together
+    #[default]
+    Fade,
+    /// Show one character after another
+    TypewriterChars,
+    /// Show one word after another
+    TypewriterWords,
+    /// Fade each section after the previous one
+    StaggeredSections,
+    /// Fade sweeping through the glyphs
+    Wave,
+}
+
+impl SplashTextAnimation {
+    // Split the sections on the units revealed by the animation
+    pub(crate) fn split(&self, sections: Vec<TextSection>) -> Vec<TextSection> {
+        let split: fn(&str) -> Vec<&str> = match self {
+            SplashTextAnimation::Fade | SplashTextAnimation::StaggeredSections => return sections,
+            SplashTextAnimation::TypewriterChars | SplashTextAnimation::Wave => {
+                |value| value.split_inclusive(|_| true).collect()
+            }
+            SplashTextAnimation::TypewriterWords => {
+                |value| value.split_inclusive(char::is_whitespace).collect()
+            }
+        };
+        sections
+            .iter()
+            .flat_map(|section| {
+                split(&section.value)
+                    .into_iter()
+                    .map(|value| TextSection::new(value, section.style.clone()))
+            })
+            .collect()
+    }
+
+    // Visibility of the section `i` of `count`
+    fn alpha(&self, i: usize, count: usize, ratio: f32) -> f32 {
+        let (i, count) = (i as f32, count as f32);
+        match self {
+            SplashTextAnimation::Fade => ratio,
+            SplashTextAnimation::TypewriterChars | SplashTextAnimation::TypewriterWords => {
+                if ratio * count > i {
+                    1.
+                } else {
+                    0.
+                }
+            }
+            SplashTextAnimation::StaggeredSections => (ratio * count - i).clamp(0., 1.),
+            SplashTextAnimation::Wave => {
+                ((ratio * (count + WAVE_WIDTH) - i) / WAVE_WIDTH).clamp(0., 1.)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SplashSpriteColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// Lens for interpolating Bevy Text sections. The single parameter is a reference to the color of each section.
-pub struct SplashTextColorLens(Vec<Color>);
+pub struct SplashTextColorLens(Vec<Color>, SplashTextAnimation);
 
 impl SplashTextColorLens {
     /// Create instance of Text Lens
     ///
     /// * `colors`: Each color refers to a section and is placed in order.
     pub fn new(colors: Vec<Color>) -> Self {
-        Self(colors)
+        Self(colors, SplashTextAnimation::Fade)
+    }
+
+    /// Reveal the sections with `animation` instead of fading them together
+    pub fn with_animation(mut self, animation: SplashTextAnimation) -> Self {
+        self.1 = animation;
+        self
     }
 }
 
 impl Lens<Text> for SplashTextColorLens {
     fn lerp(&mut self, target: &mut dyn Targetable<Text>, ratio: f32) {
+        let count = target.sections.len();
         target
             .sections
             .iter_mut()
             .enumerate()
             .for_each(|(i, section)| {
                 use crate::ColorLerper as _;
-                let value = self.0[i].with_alpha(0.).lerp(&self.0[i], ratio);
+                let value = self.0[i]
+                    .with_alpha(0.)
+                    .lerp(&self.0[i], self.1.alpha(i, count, ratio));
                 section.style.color = value;
             });
     }
@@ -47,6 +125,7 @@ impl InstanceLens for SplashImageColorLens {
     }
 }
 
+#[cfg(feature = "ui")]
 impl Lens<UiImage> for SplashImageColorLens {
     fn lerp(&mut self, target: &mut dyn Targetable<UiImage>, ratio: f32) {
         use crate::ColorLerper as _;
@@ -54,3 +133,107 @@ impl Lens<UiImage> for SplashImageColorLens {
         target.color = value;
     }
 }
+
+impl InstanceLens for SplashSpriteColorLens {
+    fn create(start: Color, end: Color) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Lens<Sprite> for SplashSpriteColorLens {
+    fn lerp(&mut self, target: &mut dyn Targetable<Sprite>, ratio: f32) {
+        use crate::ColorLerper as _;
+        let value = self.start.lerp(&self.end, ratio);
+        target.color = value;
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// Lens interpolating the whole transform, rotations are slerped
+pub struct SplashTransformLens {
+    /// Start transform.
+    pub start: Transform,
+    /// End transform.
+    pub end: Transform,
+}
+
+impl Lens<Transform> for SplashTransformLens {
+    fn lerp(&mut self, target: &mut dyn Targetable<Transform>, ratio: f32) {
+        target.translation = self.start.translation.lerp(self.end.translation, ratio);
+        target.rotation = self.start.rotation.slerp(self.end.rotation, ratio);
+        target.scale = self.start.scale.lerp(self.end.scale, ratio);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(value: &str) -> TextSection {
+        TextSection::new(value, TextStyle::default())
+    }
+
+    fn values(sections: &[TextSection]) -> Vec<&str> {
+        sections.iter().map(|s| s.value.as_str()).collect()
+    }
+
+    #[test]
+    fn split_chars() {
+        let sections = vec![section("ab"), section("é ")];
+        let split = SplashTextAnimation::TypewriterChars.split(sections.clone());
+        assert_eq!(values(&split), ["a", "b", "é", " "]);
+        let split = SplashTextAnimation::Wave.split(sections);
+        assert_eq!(values(&split), ["a", "b", "é", " "]);
+    }
+
+    #[test]
+    fn split_words() {
+        let sections = vec![section("made with "), section("bevy")];
+        let split = SplashTextAnimation::TypewriterWords.split(sections);
+        assert_eq!(values(&split), ["made ", "with ", "bevy"]);
+    }
+
+    #[test]
+    fn split_keeps_sections() {
+        let sections = vec![section("made with "), section("bevy")];
+        let split = SplashTextAnimation::Fade.split(sections.clone());
+        assert_eq!(values(&split), ["made with ", "bevy"]);
+        let split = SplashTextAnimation::StaggeredSections.split(sections);
+        assert_eq!(values(&split), ["made with ", "bevy"]);
+    }
+
+    #[test]
+    fn alpha_fade() {
+        for i in 0..4 {
+            assert_eq!(SplashTextAnimation::Fade.alpha(i, 4, 0.25), 0.25);
+        }
+    }
+
+    #[test]
+    fn alpha_typewriter() {
+        let animation = SplashTextAnimation::TypewriterChars;
+        assert_eq!(animation.alpha(0, 4, 0.), 0.);
+        assert_eq!(animation.alpha(0, 4, 0.1), 1.);
+        assert_eq!(animation.alpha(1, 4, 0.25), 0.);
+        assert_eq!(animation.alpha(1, 4, 0.3), 1.);
+        assert_eq!(animation.alpha(3, 4, 1.), 1.);
+    }
+
+    #[test]
+    fn alpha_staggered() {
+        let animation = SplashTextAnimation::StaggeredSections;
+        assert_eq!(animation.alpha(0, 2, 0.25), 0.5);
+        assert_eq!(animation.alpha(1, 2, 0.25), 0.);
+        assert_eq!(animation.alpha(0, 2, 0.75), 1.);
+        assert_eq!(animation.alpha(1, 2, 0.75), 0.5);
+    }
+
+    #[test]
+    fn alpha_wave() {
+        let animation = SplashTextAnimation::Wave;
+        assert_eq!(animation.alpha(0, 4, 0.), 0.);
+        assert_eq!(animation.alpha(0, 4, 0.25), 0.5);
+        assert_eq!(animation.alpha(3, 4, 0.25), 0.);
+        assert_eq!(animation.alpha(3, 4, 1.), 1.);
+    }
+}
@@ -1,23 +1,90 @@
+use std::sync::Arc;
 use std::time::Duration;
 
+use bevy::ecs::system::SystemId;
 use bevy::prelude::*;
 use bevy::state::state::FreelyMutableState;
+use bevy::time::TimeSystem;
 use bevy_tweening::*;
 
+mod animation;
+#[cfg(feature = "audio")]
+mod audio;
+mod background;
+mod camera;
+#[cfg(feature = "capture")]
+mod capture;
+mod condition;
 mod lens;
+#[cfg(feature = "ui")]
+mod material;
+#[cfg(feature = "ui")]
+mod responsive;
+#[cfg(feature = "scene")]
+mod scene;
 mod splash;
+#[cfg(feature = "ui")]
+mod style;
 mod systems;
+mod transition;
+mod world;
 
+use animation::animate_frames;
+pub use animation::{
+    SplashAnimationMode, SplashFramesSource, SplashImageSequence, SplashSpriteSheet,
+};
+#[cfg(feature = "audio")]
+use audio::{brand_audio, fade_audio, start_music};
+#[cfg(feature = "audio")]
+pub use audio::{SplashAudio, SplashMusic, SplashMusicTrack};
+#[cfg(feature = "ui")]
+use background::{fit_images, size_image_boxes};
+pub use background::{SplashBackgroundType, SplashImageFit};
+use camera::spawn_camera;
+pub use camera::SplashCamera;
+#[cfg(feature = "capture")]
+use capture::build_capture;
+#[cfg(feature = "capture")]
+pub use capture::SplashCapture;
+use condition::filter_splash;
+pub use condition::{SplashCondition, SplashFileStorage, SplashSeen, SplashShow, SplashStorage};
 pub use lens::*;
+#[cfg(feature = "ui")]
+pub use material::{
+    SplashMaterial, SplashMaterialBackground, SplashMaterialPlugin, SplashMaterialProgress,
+};
+#[cfg(feature = "ui")]
+use responsive::responsive_layouts;
+#[cfg(feature = "ui")]
+pub use responsive::SplashBreakpoint;
+#[cfg(feature = "scene")]
+pub use scene::SplashScene;
+#[cfg(feature = "scene")]
+use scene::{despawn_scenes, scene_layers, spawn_scenes};
 use splash::create_splash;
+#[cfg(feature = "ui")]
+pub use style::SplashStyle;
 pub use systems::ClearSplash;
 use systems::*;
+#[cfg(feature = "ui")]
+use transition::animate_transitions;
+pub use transition::SplashTransition;
+pub use world::SplashRender;
+use world::{layout_world_brands, update_world_background};
+#[cfg(not(feature = "ui"))]
+pub use world::{BackgroundColor, Val};
 
 #[derive(Clone, Component)]
 pub enum SplashAssetType {
     /// Content and Font
     SingleText(Text, String),
     SingleImage(String),
+    /// Image and grid of the animated frames
+    SpriteSheet(String, SplashSpriteSheet),
+    ImageSequence(SplashImageSequence),
+    /// glTF model with its own camera and light
+    #[cfg(feature = "scene")]
+    Scene(SplashScene),
 }
 
 #[derive(Clone, Component, Default, PartialEq, Eq)]
@@ -25,6 +92,23 @@ pub enum SplashType {
     #[default]
     List,
     Grid,
+    /// Brands placed by their `anchor`, centered without one
+    Free,
+}
+
+/// Place of a brand on the screen, out of the list or grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplashAnchor {
+    #[default]
+    Center,
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
 }
 
 #[derive(Clone)]
@@ -36,6 +120,45 @@ pub struct SplashItem {
     pub ease_function: EaseMethod,
     pub duration: Duration,
     pub is_static: bool,
+    /// Fit of `SingleImage` brands on `width` and `height`, the sizes left
+    /// to auto take the size of the image
+    pub fit: SplashImageFit,
+    pub show: SplashShow,
+    /// Delay after the screen starts, replaces the `stagger` of the screen
+    pub start: Option<Duration>,
+    pub anchor: Option<SplashAnchor>,
+    /// Distance to the edges of the `anchor`, ignored on centered axis
+    pub anchor_offset: (Val, Val),
+    #[cfg(feature = "ui")]
+    pub style: SplashStyle,
+    /// Reveal of `SingleText` brands
+    pub text_animation: SplashTextAnimation,
+    #[cfg(feature = "audio")]
+    pub audio: Option<SplashAudio>,
+}
+
+impl Default for SplashItem {
+    fn default() -> Self {
+        Self {
+            asset: SplashAssetType::SingleImage(String::new()),
+            tint: Color::WHITE,
+            width: Val::Auto,
+            height: Val::Auto,
+            ease_function: EaseMethod::Linear,
+            duration: Duration::from_secs(1),
+            is_static: false,
+            fit: SplashImageFit::Stretch,
+            show: SplashShow::Always,
+            start: None,
+            anchor: None,
+            anchor_offset: (Val::ZERO, Val::ZERO),
+            #[cfg(feature = "ui")]
+            style: SplashStyle::default(),
+            text_animation: SplashTextAnimation::Fade,
+            #[cfg(feature = "audio")]
+            audio: None,
+        }
+    }
 }
 
 #[derive(Clone, Component, Default, PartialEq, Eq)]
@@ -51,23 +174,192 @@ pub struct SplashScreen {
     pub splash_type: SplashType,
     pub wait_to_start: WaitScreenType,
     pub background_color: BackgroundColor,
+    /// Image or gradient over `background_color`, a color replaces it
+    pub background: Option<SplashBackgroundType>,
+    pub show: SplashShow,
+    pub transition: SplashTransition,
+    /// Delay between the brands, shown one after another
+    pub stagger: Duration,
+    /// Style of the node containing the brands
+    #[cfg(feature = "ui")]
+    pub style: SplashStyle,
+    /// Alternate layouts, the first matching the window is used
+    #[cfg(feature = "ui")]
+    pub breakpoints: Vec<SplashBreakpoint>,
 }
 
 #[derive(Event)]
 pub struct SplashScreenSkipEvent;
 
-#[derive(Default, Clone, Resource)]
-pub(crate) struct SplashScreenSkipable(bool, bool);
+/// Sent when a splash sequence ends, `entity` is the one returned by
+/// [`SplashCommandsExt::play_splash`]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SplashScreenEndEvent {
+    pub entity: Entity,
+    pub skipped: bool,
+}
+
+/// What to do when a splash sequence ends (also when is skipped)
+#[derive(Clone)]
+pub struct SplashEndAction(Arc<dyn Fn(&mut World) + Send + Sync>);
+
+impl SplashEndAction {
+    pub fn new(action: impl Fn(&mut World) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(action))
+    }
+
+    pub fn next_state<S: FreelyMutableState>(state: S) -> Self {
+        Self::new(move |world| world.insert_resource(NextState::Pending(state.clone())))
+    }
+
+    /// Pick the next state from the world, e.g. tutorial on first launch
+    pub fn next_state_with<S: FreelyMutableState>(
+        f: impl Fn(&World) -> S + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(move |world| {
+            let state = f(world);
+            world.insert_resource(NextState::Pending(state));
+        })
+    }
+
+    pub fn run_system(system: SystemId) -> Self {
+        Self::new(move |world| {
+            if let Err(e) = world.run_system(system) {
+                error!("Failed to run splash end system: {e}");
+            }
+        })
+    }
+
+    pub fn send_event<E: Event + Clone>(event: E) -> Self {
+        Self::new(move |world| {
+            world.send_event(event.clone());
+        })
+    }
+}
+
+/// Play a sequence of screens at runtime, without a dedicated state
+#[derive(Event, Clone, Default)]
+pub struct PlaySplash {
+    pub screens: Vec<SplashScreen>,
+    pub skipable: bool,
+    /// Only skip on [`SplashScreenSkipEvent`], not on key, mouse, touch or gamepad presses
+    pub ignore_default_events: bool,
+    pub on_end: Option<SplashEndAction>,
+    /// Start again from this screen when the last one ends, until skipped.
+    /// Index on `screens`, counting the ones hidden by their `show`
+    pub loop_from: Option<usize>,
+    /// Space kept free of brands on the window edges,
+    /// `UiRect::all(Val::Percent(5.))` for the title safe area of TVs
+    #[cfg(feature = "ui")]
+    pub safe_area: UiRect,
+    pub camera: SplashCamera,
+    pub render: SplashRender,
+    /// Render offscreen and write the frames to disk, instead of the window
+    #[cfg(feature = "capture")]
+    pub capture: Option<SplashCapture>,
+    /// Advance the splash by this step every frame, regardless of real time,
+    /// so frame N always shows the same. Changes the [`TimeUpdateStrategy`](bevy::time::TimeUpdateStrategy)
+    /// of the app while the splash plays, restoring it after. Sequences without
+    /// a step wait for it to end, and the other way around. Sequences playing
+    /// together use the step of the first one
+    pub fixed_step: Option<Duration>,
+    #[cfg(feature = "audio")]
+    pub music: Option<SplashMusic>,
+}
+
+pub trait SplashCommandsExt {
+    /// Spawn a splash sequence, the returned entity is reported back
+    /// on [`SplashScreenEndEvent`]
+    fn play_splash(&mut self, splash: PlaySplash) -> Entity;
+}
+
+impl SplashCommandsExt for Commands<'_, '_> {
+    fn play_splash(&mut self, splash: PlaySplash) -> Entity {
+        self.spawn(SplashSequence::from(splash)).id()
+    }
+}
+
+#[derive(Default, Clone)]
+pub(crate) struct SplashScreens(pub(crate) Vec<SplashScreen>);
+
+/// Systems and events needed to play splash sequences,
+/// added by [`SplashPlugin`] or alone to only use [`PlaySplash`]
+pub struct SplashSequencePlugin;
+
+impl Plugin for SplashSequencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TweeningPlugin)
+            .add_event::<SplashScreenSkipEvent>()
+            .add_event::<SplashScreenEndEvent>()
+            .add_event::<PlaySplash>()
+            .add_systems(First, fixed_step_time.before(TimeSystem))
+            // Brands animate from the frame they are created, same as the timeline
+            .configure_sets(
+                Update,
+                AnimationSystem::AnimationUpdate
+                    .after(create_splash)
+                    .before(update_splash),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_world_background.after(update_splash),
+                    layout_world_brands.after(create_splash),
+                    animate_frames.after(create_splash),
+                    spawn_camera.after(filter_splash).before(create_splash),
+                    (
+                        play_splash_event,
+                        filter_splash,
+                        create_splash,
+                        update_splash,
+                        splash_skip,
+                    )
+                        .chain(),
+                ),
+            );
+
+        #[cfg(feature = "ui")]
+        app.add_systems(
+            Update,
+            (
+                component_animator_system::<UiImage>.in_set(AnimationSystem::AnimationUpdate),
+                fit_images,
+                size_image_boxes,
+                animate_transitions.after(update_splash),
+                responsive_layouts.after(create_splash),
+            ),
+        );
+
+        #[cfg(feature = "audio")]
+        app.add_systems(
+            Update,
+            (
+                start_music.after(create_splash).before(update_splash),
+                (brand_audio, fade_audio).chain(),
+            ),
+        );
+
+        #[cfg(feature = "scene")]
+        app.add_systems(
+            Update,
+            (
+                spawn_scenes.after(create_splash),
+                scene_layers,
+                despawn_scenes,
+            ),
+        );
+    }
 
-#[derive(Default, Clone, Resource)]
-pub(crate) struct SplashScreens(Vec<SplashScreen>);
+    fn finish(&self, _app: &mut App) {
+        // The render graph is ready once the render plugin is built
+        #[cfg(feature = "capture")]
+        build_capture(_app);
+    }
+}
 
 pub struct SplashPlugin<S: FreelyMutableState> {
     state: S,
-    next: S,
-    skipable: bool,
-    ignore_default_events: bool,
-    screens: SplashScreens,
+    splash: PlaySplash,
 }
 
 impl<S> SplashPlugin<S>
@@ -76,26 +368,80 @@ where
 {
     pub fn new(splash_state: S, next_state: S) -> Self {
         Self {
-            skipable: false,
-            ignore_default_events: false,
             state: splash_state,
-            next: next_state,
-            screens: SplashScreens::default(),
+            splash: PlaySplash {
+                on_end: Some(SplashEndAction::next_state(next_state)),
+                ..default()
+            },
         }
     }
 
     pub fn skipable(mut self) -> Self {
-        self.skipable = true;
+        self.splash.skipable = true;
         self
     }
 
     pub fn ignore_default_events(mut self) -> Self {
-        self.ignore_default_events = true;
+        self.splash.ignore_default_events = true;
+        self
+    }
+
+    /// Replace the default change to `next_state` when the splash ends.
+    /// Without states at all, use [`SplashSequencePlugin`] and [`PlaySplash::on_end`]
+    pub fn on_end(mut self, action: SplashEndAction) -> Self {
+        self.splash.on_end = Some(action);
+        self
+    }
+
+    /// Play again from `screen` when the last screen ends, until is skipped.
+    /// Use `0` to loop the whole sequence
+    pub fn looping(mut self, screen: usize) -> Self {
+        self.splash.loop_from = Some(screen);
+        self
+    }
+
+    /// Keep the brands inside the window minus `insets`
+    #[cfg(feature = "ui")]
+    pub fn safe_area(mut self, insets: UiRect) -> Self {
+        self.splash.safe_area = insets;
+        self
+    }
+
+    /// Draw with `bevy_ui` nodes or with sprites in the world
+    pub fn render(mut self, render: SplashRender) -> Self {
+        self.splash.render = render;
+        self
+    }
+
+    /// Camera or window where the splash is shown
+    pub fn camera(mut self, camera: SplashCamera) -> Self {
+        self.splash.camera = camera;
+        self
+    }
+
+    /// Advance the splash by `step` every frame, like `1/60` seconds,
+    /// for golden image tests and video capture
+    pub fn fixed_step(mut self, step: Duration) -> Self {
+        self.splash.fixed_step = Some(step);
+        self
+    }
+
+    /// Write the frames of the splash to disk instead of showing it
+    #[cfg(feature = "capture")]
+    pub fn capture(mut self, capture: SplashCapture) -> Self {
+        self.splash.capture = Some(capture);
+        self
+    }
+
+    /// Music for all the screens, fade out when the splash ends
+    #[cfg(feature = "audio")]
+    pub fn music(mut self, music: SplashMusic) -> Self {
+        self.splash.music = Some(music);
         self
     }
 
     pub fn add_screen(mut self, screen: SplashScreen) -> Self {
-        self.screens.0.push(screen);
+        self.splash.screens.push(screen);
         self
     }
 }
@@ -105,31 +451,16 @@ where
     S: FreelyMutableState,
 {
     fn build(&self, app: &mut App) {
-        if self.screens.0.is_empty() {
+        if self.splash.screens.is_empty() {
             return;
         }
 
-        app.add_plugins(TweeningPlugin)
-            .add_event::<SplashScreenSkipEvent>()
-            .insert_resource(self.screens.clone())
-            .insert_resource(SplashScreenSkipable(
-                self.skipable,
-                self.ignore_default_events,
-            ))
-            .insert_resource(MaxScreens(
-                self.screens.0.len() as u64 - 1,
-                self.next.clone(),
-                (self.screens.0.iter().map(|s| s.brands.len()).sum::<usize>() * 2) as u64,
-            ))
-            .add_systems(Startup, create_splash)
-            .add_systems(
-                Update,
-                (
-                    component_animator_system::<UiImage>.run_if(in_state(self.state.clone())),
-                    update_splash::<S>.run_if(in_state(self.state.clone())),
-                    splash_skip::<S>,
-                ),
-            );
+        if !app.is_plugin_added::<SplashSequencePlugin>() {
+            app.add_plugins(SplashSequencePlugin);
+        }
+
+        app.insert_resource(SplashIntro(self.splash.clone()))
+            .add_systems(OnEnter(self.state.clone()), create_intro);
     }
 }
 
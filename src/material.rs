@@ -0,0 +1,132 @@
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::systems::{update_splash, SplashSequence};
+
+/// Values of the splash given to the material each frame
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SplashMaterialProgress {
+    /// Seconds since the splash started
+    pub elapsed: f32,
+    /// Index of the current screen
+    pub screen: u32,
+    /// Brands visibility of the screen, `0` to `1` and back to `0`
+    pub fade: f32,
+}
+
+/// [`UiMaterial`] usable as screen background, copy the progress into its uniforms
+pub trait SplashMaterial: UiMaterial {
+    fn update(&mut self, progress: SplashMaterialProgress);
+}
+
+/// Register a [`SplashMaterial`] to use it on [`SplashBackgroundType::Material`](crate::SplashBackgroundType::Material)
+pub struct SplashMaterialPlugin<M: SplashMaterial>(PhantomData<M>);
+
+impl<M: SplashMaterial> Default for SplashMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M: SplashMaterial> Plugin for SplashMaterialPlugin<M>
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.add_plugins(UiMaterialPlugin::<M>::default())
+            .add_systems(Update, update_splash_material::<M>.after(update_splash));
+    }
+}
+
+type SpawnMaterial = dyn Fn(&mut ChildBuilder, SplashMaterialScreen) + Send + Sync;
+
+/// Material node spawned behind the screen brands
+#[derive(Clone)]
+pub struct SplashMaterialBackground(Arc<SpawnMaterial>);
+
+impl SplashMaterialBackground {
+    pub fn new<M: SplashMaterial>(material: Handle<M>) -> Self {
+        Self(Arc::new(move |cmd, screen| {
+            cmd.spawn(MaterialNodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                material: material.clone(),
+                visibility: Visibility::Hidden,
+                ..default()
+            })
+            .insert(screen);
+        }))
+    }
+
+    pub(crate) fn spawn(&self, cmd: &mut ChildBuilder, screen: SplashMaterialScreen) {
+        (self.0)(cmd, screen)
+    }
+}
+
+impl fmt::Debug for SplashMaterialBackground {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SplashMaterialBackground")
+    }
+}
+
+// Screen of a material node and when its brands are shown
+#[derive(Component)]
+pub(crate) struct SplashMaterialScreen {
+    pub(crate) root: Entity,
+    pub(crate) screen: usize,
+    pub(crate) start: Duration,
+    pub(crate) length: Duration,
+}
+
+impl SplashMaterialScreen {
+    fn fade(&self, elapsed: Duration) -> f32 {
+        let t = elapsed.saturating_sub(self.start).as_secs_f32()
+            / self.length.as_secs_f32().max(f32::EPSILON);
+        if t <= 1. {
+            t
+        } else {
+            (2. - t).max(0.)
+        }
+    }
+}
+
+//
+// Show the material of the current screen and feed its progress
+//
+pub(crate) fn update_splash_material<M: SplashMaterial>(
+    sequences: Query<&SplashSequence>,
+    mut nodes: Query<(&SplashMaterialScreen, &Handle<M>, &mut Visibility)>,
+    mut materials: ResMut<Assets<M>>,
+) {
+    for (node, handle, mut visibility) in nodes.iter_mut() {
+        let Ok(sequence) = sequences.get(node.root) else {
+            continue;
+        };
+        let current = sequence.screen == node.screen;
+        visibility.set_if_neq(if current {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        if !current {
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(handle) {
+            material.update(SplashMaterialProgress {
+                elapsed: sequence.elapsed.as_secs_f32(),
+                screen: node.screen as u32,
+                fade: node.fade(sequence.elapsed),
+            });
+        }
+    }
+}
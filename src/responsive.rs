@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+use crate::splash::{brand_style, screen_style};
+use crate::{SplashItem, SplashScreen, SplashStyle, SplashType};
+
+/// Layout of a screen used while the window is within the limits
+#[derive(Clone, Default)]
+pub struct SplashBreakpoint {
+    /// Window width in logical pixels
+    pub max_width: Option<f32>,
+    /// Window width divided by its height, below `1` is portrait
+    pub max_aspect_ratio: Option<f32>,
+    pub splash_type: Option<SplashType>,
+    /// Replaces the `style` of the screen
+    pub style: Option<SplashStyle>,
+    /// Width and height of every brand
+    pub brand_size: Option<(Val, Val)>,
+}
+
+impl SplashBreakpoint {
+    fn matches(&self, window: Vec2) -> bool {
+        self.max_width.map_or(true, |max| window.x <= max)
+            && self
+                .max_aspect_ratio
+                .map_or(true, |max| window.x / window.y.max(1.) <= max)
+    }
+}
+
+// Content node of a screen with breakpoints
+#[derive(Component)]
+pub(crate) struct SplashLayout {
+    splash_type: SplashType,
+    style: SplashStyle,
+    breakpoints: Vec<SplashBreakpoint>,
+    // Node of each brand and the brand it shows
+    brands: Vec<(Entity, SplashItem)>,
+    current: Option<usize>,
+}
+
+impl SplashLayout {
+    pub(crate) fn new(screen: &SplashScreen, nodes: Vec<Entity>) -> Self {
+        Self {
+            splash_type: screen.splash_type.clone(),
+            style: screen.style.clone(),
+            breakpoints: screen.breakpoints.clone(),
+            brands: nodes.into_iter().zip(screen.brands.clone()).collect(),
+            current: None,
+        }
+    }
+}
+
+//
+// Switch the layout of the screens when the window is resized
+//
+pub(crate) fn responsive_layouts(
+    mut resized: EventReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut layouts: Query<(Entity, &mut SplashLayout)>,
+    mut styles: Query<&mut Style>,
+) {
+    let resized = resized.read().count() > 0;
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let size = window.size();
+
+    for (entity, mut layout) in layouts.iter_mut() {
+        if !resized && !layout.is_added() {
+            continue;
+        }
+        let current = layout.breakpoints.iter().position(|b| b.matches(size));
+        if current == layout.current {
+            continue;
+        }
+        layout.current = current;
+
+        let breakpoint = current.map(|i| &layout.breakpoints[i]);
+        let splash_type = breakpoint
+            .and_then(|b| b.splash_type.as_ref())
+            .unwrap_or(&layout.splash_type);
+        let overrides = breakpoint
+            .and_then(|b| b.style.as_ref())
+            .unwrap_or(&layout.style);
+        let content = screen_style(splash_type, overrides);
+        let free = *splash_type == SplashType::Free;
+
+        // Brands are styled again, the splash type may add or remove the anchors
+        for (node, brand) in layout.brands.iter() {
+            let mut brand = brand.clone();
+            if let Some((width, height)) = breakpoint.and_then(|b| b.brand_size) {
+                brand.width = width;
+                brand.height = height;
+            }
+            if let Ok(mut style) = styles.get_mut(*node) {
+                *style = brand_style(&brand, &content, free);
+            }
+        }
+        if let Ok(mut style) = styles.get_mut(entity) {
+            *style = content;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplashImageFit;
+
+    #[test]
+    fn matches_width() {
+        let breakpoint = SplashBreakpoint {
+            max_width: Some(600.),
+            ..default()
+        };
+        assert!(breakpoint.matches(Vec2::new(600., 800.)));
+        assert!(breakpoint.matches(Vec2::new(320., 800.)));
+        assert!(!breakpoint.matches(Vec2::new(601., 800.)));
+    }
+
+    #[test]
+    fn matches_aspect_ratio() {
+        let breakpoint = SplashBreakpoint {
+            max_aspect_ratio: Some(1.),
+            ..default()
+        };
+        assert!(breakpoint.matches(Vec2::new(720., 1280.)));
+        assert!(breakpoint.matches(Vec2::new(800., 800.)));
+        assert!(!breakpoint.matches(Vec2::new(1280., 720.)));
+        // A window without height is not divided by zero
+        assert!(!breakpoint.matches(Vec2::new(1280., 0.)));
+    }
+
+    #[test]
+    fn matches_both_limits() {
+        let breakpoint = SplashBreakpoint {
+            max_width: Some(1000.),
+            max_aspect_ratio: Some(1.),
+            ..default()
+        };
+        assert!(breakpoint.matches(Vec2::new(720., 1280.)));
+        assert!(!breakpoint.matches(Vec2::new(1200., 1600.)));
+        assert!(!breakpoint.matches(Vec2::new(900., 600.)));
+        assert!(SplashBreakpoint::default().matches(Vec2::new(1920., 1080.)));
+    }
+
+    #[test]
+    fn switch_brand_anchors() {
+        let mut app = App::new();
+        app.add_event::<WindowResized>()
+            .add_systems(Update, responsive_layouts);
+        let window = app
+            .world_mut()
+            .spawn((
+                Window {
+                    resolution: (400., 800.).into(),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        let brand = app.world_mut().spawn(Style::default()).id();
+        let screen = SplashScreen {
+            brands: vec![SplashItem {
+                width: Val::Px(100.),
+                fit: SplashImageFit::FitWidth,
+                ..default()
+            }],
+            breakpoints: vec![SplashBreakpoint {
+                max_aspect_ratio: Some(1.),
+                splash_type: Some(SplashType::Free),
+                brand_size: Some((Val::Px(50.), Val::Px(50.))),
+                ..default()
+            }],
+            ..default()
+        };
+        app.world_mut()
+            .spawn((Style::default(), SplashLayout::new(&screen, vec![brand])));
+
+        // Portrait uses the free breakpoint, the fit keeps the height to auto
+        app.update();
+        let style = app.world().get::<Style>(brand).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.width, Val::Px(50.));
+        assert_eq!(style.height, Val::Auto);
+
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(1200., 800.);
+        app.world_mut().send_event(WindowResized {
+            window,
+            width: 1200.,
+            height: 800.,
+        });
+        app.update();
+        let style = app.world().get::<Style>(brand).unwrap();
+        assert_eq!(style.position_type, PositionType::Relative);
+        assert_eq!(style.width, Val::Px(100.));
+    }
+}
@@ -0,0 +1,240 @@
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy::render::{camera::RenderTarget, view::RenderLayers};
+#[cfg(feature = "ui")]
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+};
+use bevy_tweening::*;
+
+use crate::{SplashItem, SplashTransformLens};
+
+/// 3d model shown as a brand, lit and rendered by its own camera and light
+#[derive(Clone, Debug)]
+pub struct SplashScene {
+    /// Scene of a glTF file, like `logo.glb#Scene0`
+    pub path: String,
+    /// Transform of the model while hidden
+    pub from: Transform,
+    /// Transform of the model while shown
+    pub to: Transform,
+    pub camera: Transform,
+    pub light: Transform,
+    /// Render into a texture of this size shown on the brand node.
+    /// Without it, or on world splashes, the model is drawn over the window
+    pub texture: Option<UVec2>,
+    /// Render layer of the model, camera and light, apart from the app ones
+    pub layer: usize,
+}
+
+impl SplashScene {
+    /// Model growing while it turns from its side to face the camera
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            from: Transform::from_rotation(Quat::from_rotation_y(-FRAC_PI_2))
+                .with_scale(Vec3::ZERO),
+            to: Transform::IDENTITY,
+            camera: Transform::from_xyz(0., 0., 5.).looking_at(Vec3::ZERO, Vec3::Y),
+            light: Transform::from_xyz(2., 4., 3.).looking_at(Vec3::ZERO, Vec3::Y),
+            texture: None,
+            layer: 1,
+        }
+    }
+
+    pub fn with_texture(mut self, size: UVec2) -> Self {
+        self.texture = Some(size);
+        self
+    }
+}
+
+// Brand waiting for the model, camera and light of its scene
+#[derive(Component)]
+pub(crate) struct SplashSceneBrand {
+    scene: SplashScene,
+    ease_function: EaseMethod,
+    duration: Duration,
+    max_duration: Duration,
+}
+
+impl SplashSceneBrand {
+    pub(crate) fn new(scene: &SplashScene, brand: &SplashItem, max_duration: Duration) -> Self {
+        Self {
+            scene: scene.clone(),
+            ease_function: brand.ease_function,
+            duration: brand.duration,
+            max_duration,
+        }
+    }
+
+    // Same timeline than the fade of the brand
+    fn animator(&self) -> Animator<Transform> {
+        let SplashScene { from, to, .. } = self.scene;
+        Animator::new(
+            Tween::new(
+                self.ease_function,
+                Duration::from_secs(1),
+                SplashTransformLens {
+                    start: from,
+                    end: from,
+                },
+            )
+            .then(
+                Delay::new(self.max_duration).then(
+                    Tween::new(
+                        self.ease_function,
+                        self.duration,
+                        SplashTransformLens {
+                            start: from,
+                            end: to,
+                        },
+                    )
+                    .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+                    .with_repeat_count(RepeatCount::Finite(2)),
+                ),
+            ),
+        )
+    }
+}
+
+// Brand timing the fade, transparent when the model is not drawn on it
+pub(crate) fn placeholder_brand(brand: &SplashItem, textured: bool) -> SplashItem {
+    SplashItem {
+        tint: if textured { brand.tint } else { Color::NONE },
+        ..brand.clone()
+    }
+}
+
+// Entity of a scene rig, despawned with its brand
+#[derive(Component)]
+pub(crate) struct SplashSceneRig(Entity);
+
+// Model root, its descendants are moved to the layer of the rig
+#[derive(Component)]
+pub(crate) struct SplashSceneModel;
+
+#[cfg(feature = "ui")]
+fn render_texture(size: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+//
+// Spawn the model, camera and light of new scene brands
+//
+pub(crate) fn spawn_scenes(
+    mut cmd: Commands,
+    assets: Res<AssetServer>,
+    #[cfg(feature = "ui")] mut images: ResMut<Assets<Image>>,
+    #[cfg(feature = "ui")] mut nodes: Query<&mut UiImage>,
+    brands: Query<(Entity, &SplashSceneBrand), Added<SplashSceneBrand>>,
+) {
+    for (brand, scene_brand) in brands.iter() {
+        let scene = &scene_brand.scene;
+        let layer = RenderLayers::layer(scene.layer);
+        // Shown on the brand node, world brands have none
+        #[cfg(feature = "ui")]
+        let texture = scene
+            .texture
+            .zip(nodes.get_mut(brand).ok())
+            .map(|(size, mut image)| {
+                image.texture = images.add(render_texture(size));
+                image.texture.clone()
+            });
+        #[cfg(not(feature = "ui"))]
+        let texture: Option<Handle<Image>> = None;
+        let camera = match texture {
+            Some(texture) => Camera {
+                target: RenderTarget::Image(texture),
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                ..default()
+            },
+            // Over the splash camera
+            _ => Camera {
+                order: 2,
+                clear_color: ClearColorConfig::None,
+                ..default()
+            },
+        };
+
+        cmd.spawn((
+            Camera3dBundle {
+                camera,
+                tonemapping: Tonemapping::None,
+                transform: scene.camera,
+                ..default()
+            },
+            layer.clone(),
+            SplashSceneRig(brand),
+        ));
+        cmd.spawn((
+            DirectionalLightBundle {
+                transform: scene.light,
+                ..default()
+            },
+            layer.clone(),
+            SplashSceneRig(brand),
+        ));
+        cmd.spawn((
+            SceneBundle {
+                scene: assets.load(&scene.path),
+                transform: scene.from,
+                ..default()
+            },
+            scene_brand.animator(),
+            layer,
+            SplashSceneModel,
+            SplashSceneRig(brand),
+        ));
+    }
+}
+
+//
+// Keep the meshes of loaded models on the layer of their rig
+//
+pub(crate) fn scene_layers(
+    mut cmd: Commands,
+    models: Query<(Entity, &RenderLayers), With<SplashSceneModel>>,
+    children: Query<&Children>,
+    unlayered: Query<(), Without<RenderLayers>>,
+) {
+    for (model, layer) in models.iter() {
+        for entity in children.iter_descendants(model) {
+            if unlayered.contains(entity) {
+                cmd.entity(entity).insert(layer.clone());
+            }
+        }
+    }
+}
+
+//
+// Remove the rigs of despawned brands
+//
+pub(crate) fn despawn_scenes(
+    mut cmd: Commands,
+    entities: &Entities,
+    rigs: Query<(Entity, &SplashSceneRig)>,
+) {
+    for (entity, rig) in rigs.iter() {
+        if !entities.contains(rig.0) {
+            cmd.entity(entity).despawn_recursive();
+        }
+    }
+}
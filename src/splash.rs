@@ -1,21 +1,44 @@
 use std::time::Duration;
 
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use bevy_tweening::*;
 
+#[cfg(feature = "ui")]
 use crate::{
-    systems::{ClearSplash, SplashBackground},
-    InstanceLens, SplashAssetType, SplashImageColorLens, SplashItem, SplashScreens,
-    SplashTextColorLens, SplashType, WaitScreenType,
+    animation::SplashFrames,
+    background::{screen_color, set_background_image, SplashImageBox, SplashImageFitted},
+    material::SplashMaterialScreen,
+    responsive::SplashLayout,
+    transition::SplashScreenTransition,
+    world::SplashRender,
+    SplashAnchor, SplashAssetType, SplashBackgroundType, SplashImageColorLens, SplashImageFit,
+    SplashStyle, SplashTransition, SplashType,
+};
+use crate::{
+    systems::{
+        splash_end, ClearSplash, SplashBackground, SplashFixedStep, SplashParent, SplashSequence,
+    },
+    world::{create_world_splash, world_size},
+    InstanceLens, SplashItem, SplashScreen, SplashScreenEndEvent, SplashScreens,
+    SplashTextColorLens, WaitScreenType,
 };
 
-fn get_max_duration(screens: SplashScreens, curr_screen: usize) -> Duration {
+// Delay of the brand after the screen starts
+pub(crate) fn get_brand_start(screen: &SplashScreen, i_brand: usize) -> Duration {
+    screen.brands[i_brand]
+        .start
+        .unwrap_or(screen.stagger * i_brand as u32)
+}
+
+fn get_max_duration(screens: &SplashScreens, curr_screen: usize) -> Duration {
     if curr_screen == 0 {
         return Duration::from_secs(1);
     }
     let next_screen = screens.0.get(curr_screen - 1).unwrap();
 
-    return match next_screen.wait_to_start {
+    match next_screen.wait_to_start {
         WaitScreenType::AfterEnd => Duration::from_secs(
             next_screen
                 .brands
@@ -28,148 +51,564 @@ fn get_max_duration(screens: SplashScreens, curr_screen: usize) -> Duration {
                 + 1,
         ),
         WaitScreenType::Specific(t) => t,
-    };
+    }
+}
+
+const MIN_SCREEN_DELAY: Duration = Duration::from_millis(1);
+
+// Delay of each screen brands after the fade in of the splash
+pub(crate) fn get_screen_delays(screens: &SplashScreens) -> Vec<Duration> {
+    // Staggered brands delay the next screens
+    let mut stagger = Duration::ZERO;
+    let mut previous = None;
+
+    (0..screens.0.len())
+        .map(|i_screen| {
+            let screen = &screens.0[i_screen];
+            let max_duration = get_max_duration(screens, i_screen);
+            let max_duration = match screens.0.get(i_screen.wrapping_sub(1)) {
+                Some(prev) if prev.wait_to_start == WaitScreenType::AfterEnd => {
+                    stagger += (0..prev.brands.len())
+                        .map(|i| get_brand_start(prev, i))
+                        .max()
+                        .unwrap_or_default();
+                    max_duration + stagger
+                }
+                _ => max_duration,
+            };
+            // Crossfade starts while the previous screen fades out, not before it starts
+            let delay = match previous {
+                Some(previous) => max_duration
+                    .saturating_sub(screen.transition.overlap())
+                    .max(max_duration.min(previous)),
+                None => max_duration,
+            };
+            previous = Some(delay);
+            // Tween delays can not be zero
+            delay.max(MIN_SCREEN_DELAY)
+        })
+        .collect()
 }
 
 pub(crate) fn create_splash(
     mut cmd: Commands,
     assets: Res<AssetServer>,
-    screens: Res<SplashScreens>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    #[cfg(feature = "ui")] mut images: ResMut<Assets<Image>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    sequences: Query<(Entity, &SplashSequence), Without<SplashBackground>>,
+    brands: Query<(Entity, &ClearSplash), Without<SplashSequence>>,
+    mut end: EventWriter<SplashScreenEndEvent>,
+    fixed_step: Option<Res<SplashFixedStep>>,
+) {
+    for (root, sequence) in sequences.iter() {
+        let screens = &sequence.screens;
+        if screens.0.is_empty() {
+            splash_end(&mut cmd, &mut end, (root, sequence), false, brands.iter());
+            continue;
+        }
+        // Wait for the step to drive the time, so every frame is the same,
+        // or for it to end, without applying it to a sequence not asking for it
+        if sequence.fixed_step.is_some() != fixed_step.is_some() {
+            continue;
+        }
+
+        #[cfg(feature = "ui")]
+        if sequence.render == SplashRender::Ui {
+            create_ui_splash(&mut cmd, &assets, &mut layouts, &mut images, root, sequence);
+            continue;
+        }
+
+        // World splash only uses the background colors
+        let size = world_size(sequence, windows.get_single().ok());
+        create_world_splash(&mut cmd, &assets, &mut layouts, size, root, screens);
+        cmd.entity(root).insert(SplashBackground::new(screens));
+    }
+}
+
+#[cfg(feature = "ui")]
+fn create_ui_splash(
+    cmd: &mut Commands,
+    assets: &AssetServer,
+    layouts: &mut Assets<TextureAtlasLayout>,
+    images: &mut Assets<Image>,
+    root: Entity,
+    sequence: &SplashSequence,
 ) {
+    let screens = &sequence.screens;
+    let screen_images = screens
+        .0
+        .iter()
+        .map(|s| s.background.as_ref().and_then(|b| b.image(assets, images)))
+        .collect::<Vec<_>>();
+    let mut image = None;
+
     // Background
-    cmd.spawn(NodeBundle {
-        style: Style {
-            display: Display::Flex,
-            position_type: PositionType::Absolute,
-            width: Val::Percent(100.),
-            height: Val::Percent(100.),
-            overflow: Overflow::clip(),
+    cmd.entity(root)
+        .insert(NodeBundle {
+            style: Style {
+                display: Display::Flex,
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: BackgroundColor(screen_color(&screens.0[0])),
             ..default()
-        },
-        background_color: BackgroundColor(screens.0[0].background_color.0),
-        ..default()
-    })
-    .insert(ClearSplash)
-    .insert(SplashBackground {
-        screens: screens
-            .0
-            .iter()
-            .map(|s| s.brands.len() as u64 * 2)
-            .collect(),
-        screen_colors: screens.0.iter().map(|s| s.background_color.0).collect(),
+        })
+        .insert(ClearSplash)
+        .with_children(|cmd| {
+            // Behind all the screens
+            if screen_images.iter().any(Option::is_some) {
+                image = Some(
+                    cmd.spawn(ImageBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .id(),
+                );
+            }
+            create_screens(cmd, assets, layouts, root, screens, sequence.safe_area);
+        });
+
+    if let Some(image) = image {
+        set_background_image(cmd, image, screen_images[0].as_ref());
+    }
+    cmd.entity(root).insert(SplashBackground {
+        screen_images,
+        image,
+        ..SplashBackground::new(screens)
     });
+}
+
+#[cfg(feature = "ui")]
+fn create_screens(
+    cmd: &mut ChildBuilder,
+    assets: &AssetServer,
+    layouts: &mut Assets<TextureAtlasLayout>,
+    root: Entity,
+    screens: &SplashScreens,
+    safe_area: UiRect,
+) {
+    let delays = get_screen_delays(screens);
 
     // Create each screen
     for (i_screen, screen) in screens.0.iter().enumerate() {
-        let (flex_direction, flex_wrap) = match screen.splash_type {
-            SplashType::List => (FlexDirection::Column, FlexWrap::NoWrap),
-            SplashType::Grid => (FlexDirection::Row, FlexWrap::Wrap),
-        };
-        let max_duration = get_max_duration(screens.clone(), i_screen);
+        let style = screen_style(&screen.splash_type, &screen.style);
+        let max_duration = delays[i_screen];
+        let start = Duration::from_secs(1) + max_duration;
+        let end = start
+            + (0..screen.brands.len())
+                .map(|i| get_brand_start(screen, i) + screen.brands[i].duration * 2)
+                .max()
+                .unwrap_or_default();
+        let length = (end - start) / 2;
 
-        // Parent of screen content
-        // Contains brands
-        cmd.spawn(NodeBundle {
+        if let Some(SplashBackgroundType::Material(material)) = &screen.background {
+            material.spawn(
+                cmd,
+                SplashMaterialScreen {
+                    root,
+                    screen: i_screen,
+                    start,
+                    length,
+                },
+            );
+        }
+
+        // Screen moved by the transition
+        let mut screen_cmd = cmd.spawn(NodeBundle {
             style: Style {
-                flex_wrap,
-                flex_direction,
-                display: Display::Flex,
                 position_type: PositionType::Absolute,
-                direction: Direction::LeftToRight,
-                align_items: AlignItems::Center,
-                align_content: AlignContent::Center,
-                justify_content: JustifyContent::Center,
                 width: Val::Percent(100.),
                 height: Val::Percent(100.),
                 overflow: Overflow::clip(),
                 ..default()
             },
             ..default()
-        })
-        .insert(ClearSplash)
-        .with_children(|cmd| {
-            for brand in screen.brands.iter() {
-                match &brand.asset {
-                    SplashAssetType::SingleText(text, font) => {
-                        let text = Text::from_sections(text.sections.iter().map(|s| TextSection {
-                            value: s.value.clone(),
-                            style: TextStyle {
-                                font: assets.load(font),
-                                ..s.style
-                            },
-                        }))
-                        .with_justify(text.justify);
-                        cmd.spawn((
-                            TextBundle {
-                                text: text.clone(),
-                                style: Style {
-                                    flex_direction,
-                                    flex_wrap,
-                                    width: brand.width,
-                                    height: brand.height,
-                                    ..default()
-                                },
-                                ..default()
-                            },
-                            Animator::new(
-                                Tween::new(
-                                    brand.ease_function,
-                                    Duration::from_secs(1),
-                                    SplashTextColorLens::new(
-                                        text.sections
-                                            .iter()
-                                            .map(|_| Color::WHITE.with_alpha(0.))
-                                            .collect(),
-                                    ),
-                                )
-                                .then(
-                                    Delay::new(max_duration).then(
-                                        Tween::new(
-                                            brand.ease_function,
-                                            brand.duration,
-                                            SplashTextColorLens::new(
-                                                text.sections
-                                                    .iter()
-                                                    .map(|s| s.style.color)
-                                                    .collect(),
-                                            ),
-                                        )
-                                        .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
-                                        .with_repeat_count(RepeatCount::Finite(2))
-                                        .with_completed_event(i_screen as u64),
-                                    ),
-                                ),
-                            ),
-                        ))
+        });
+        let mut target = Entity::PLACEHOLDER;
+        screen_cmd.with_children(|cmd| {
+            // Behind the brands, over the previous screen
+            if let SplashTransition::Crossfade(_) = screen.transition {
+                target = cmd
+                    .spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::NONE),
+                        ..default()
+                    })
+                    .id();
+            }
+            // Full size while the screen is clipped, keeps the brands in the safe area
+            let mut stage = cmd.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    padding: safe_area,
+                    ..default()
+                },
+                ..default()
+            });
+            if let SplashTransition::BoxWipe(_) = screen.transition {
+                target = stage.id();
+            }
+            stage.with_children(|cmd| {
+                // Parent of screen content
+                // Contains brands
+                let mut nodes = Vec::new();
+                let free = screen.splash_type == SplashType::Free;
+                let brand_styles: Vec<_> = screen
+                    .brands
+                    .iter()
+                    .map(|brand| brand_style(brand, &style, free))
+                    .collect();
+                let mut content = cmd.spawn(NodeBundle { style, ..default() });
+                content.with_children(|cmd| {
+                    for (i_brand, (brand, style)) in
+                        screen.brands.iter().zip(brand_styles).enumerate()
+                    {
+                        let node = create_brand(
+                            cmd,
+                            assets,
+                            layouts,
+                            root,
+                            brand,
+                            i_screen,
+                            max_duration + get_brand_start(screen, i_brand),
+                            style,
+                        );
+                        nodes.push(node);
                     }
-                    SplashAssetType::SingleImage(handler) => cmd.spawn((
+                });
+                if !screen.breakpoints.is_empty() {
+                    content.insert(SplashLayout::new(screen, nodes));
+                }
+            });
+
+            if let SplashTransition::DipToColor(..) = screen.transition {
+                target = cmd
+                    .spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(Color::NONE),
+                        ..default()
+                    })
+                    .id();
+            }
+        });
+
+        if screen.transition.animated() {
+            screen_cmd.insert(SplashScreenTransition {
+                root,
+                transition: screen.transition,
+                start,
+                end,
+                target,
+                color: screen_color(screen),
+            });
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+fn create_brand(
+    cmd: &mut ChildBuilder,
+    assets: &AssetServer,
+    layouts: &mut Assets<TextureAtlasLayout>,
+    root: Entity,
+    brand: &SplashItem,
+    i_screen: usize,
+    max_duration: Duration,
+    style: Style,
+) -> Entity {
+    let mut brand_cmd = match &brand.asset {
+        SplashAssetType::SingleText(text, font) => {
+            let text = brand_text(brand, text, font, assets);
+            cmd.spawn((
+                TextBundle {
+                    text: text.clone(),
+                    style,
+                    ..default()
+                },
+                create_text_animator(brand, &text, max_duration, i_screen),
+            ))
+        }
+        // Image fitted inside the box of the brand
+        SplashAssetType::SingleImage(handler) if brand.fit.clipped() => {
+            let image = assets.load(handler);
+            return cmd
+                .spawn((
+                    NodeBundle { style, ..default() },
+                    SplashImageBox(image.clone()),
+                ))
+                .with_children(|cmd| {
+                    let mut image_cmd = cmd.spawn((
                         ImageBundle {
-                            image: UiImage {
-                                texture: assets.load(handler),
-                                flip_x: false,
-                                flip_y: false,
-                                ..default()
-                            },
+                            image: UiImage::new(image),
                             style: Style {
-                                width: brand.width,
-                                height: brand.height,
+                                position_type: PositionType::Absolute,
                                 ..default()
                             },
                             ..default()
                         },
+                        SplashImageFitted(brand.fit),
                         create_animator::<UiImage, SplashImageColorLens>(
                             brand,
                             max_duration,
                             i_screen,
                         ),
-                    )),
-                };
+                    ));
+                    if brand.fit == SplashImageFit::Tile {
+                        image_cmd.insert(ImageScaleMode::Tiled {
+                            tile_x: true,
+                            tile_y: true,
+                            stretch_value: 1.,
+                        });
+                    }
+                    link_brand(&mut image_cmd, assets, root, brand, max_duration);
+                })
+                .id();
+        }
+        SplashAssetType::SingleImage(handler) => cmd.spawn((
+            ImageBundle {
+                image: UiImage {
+                    texture: assets.load(handler),
+                    flip_x: false,
+                    flip_y: false,
+                    ..default()
+                },
+                style,
+                ..default()
+            },
+            create_animator::<UiImage, SplashImageColorLens>(brand, max_duration, i_screen),
+        )),
+        SplashAssetType::SpriteSheet(handler, sheet) => cmd.spawn((
+            ImageBundle {
+                image: UiImage::new(assets.load(handler)),
+                style,
+                ..default()
+            },
+            TextureAtlas {
+                layout: layouts.add(sheet.layout()),
+                index: 0,
+            },
+            SplashFrames {
+                frames: (sheet.columns * sheet.rows) as usize,
+                fps: sheet.fps,
+                mode: sheet.mode,
+                delay: Duration::from_secs(1) + max_duration,
+                elapsed: Duration::ZERO,
+            },
+            create_animator::<UiImage, SplashImageColorLens>(brand, max_duration, i_screen),
+        )),
+        SplashAssetType::ImageSequence(sequence) => {
+            let images = sequence.load(assets);
+            cmd.spawn((
+                ImageBundle {
+                    image: UiImage::new(images.frames.first().cloned().unwrap_or_default()),
+                    style,
+                    ..default()
+                },
+                SplashFrames {
+                    frames: images.frames.len(),
+                    fps: sequence.fps,
+                    mode: sequence.mode,
+                    delay: Duration::from_secs(1) + max_duration,
+                    elapsed: Duration::ZERO,
+                },
+                images,
+                create_animator::<UiImage, SplashImageColorLens>(brand, max_duration, i_screen),
+            ))
+        }
+        #[cfg(feature = "scene")]
+        SplashAssetType::Scene(scene) => cmd.spawn((
+            ImageBundle { style, ..default() },
+            create_animator::<UiImage, SplashImageColorLens>(
+                &crate::scene::placeholder_brand(brand, scene.texture.is_some()),
+                max_duration,
+                i_screen,
+            ),
+            crate::scene::SplashSceneBrand::new(scene, brand, max_duration),
+        )),
+    };
+    link_brand(&mut brand_cmd, assets, root, brand, max_duration);
+    brand_cmd.id()
+}
+
+// Text of the brand with its font, split for the reveal
+pub(crate) fn brand_text(
+    brand: &SplashItem,
+    text: &Text,
+    font: &str,
+    assets: &AssetServer,
+) -> Text {
+    let font: Handle<Font> = assets.load(font.to_owned());
+    Text::from_sections(
+        brand.text_animation.split(
+            text.sections
+                .iter()
+                .map(|s| TextSection {
+                    value: s.value.clone(),
+                    style: TextStyle {
+                        font: font.clone(),
+                        ..s.style
+                    },
+                })
+                .collect(),
+        ),
+    )
+    .with_justify(text.justify)
+}
+
+pub(crate) fn create_text_animator(
+    brand: &SplashItem,
+    text: &Text,
+    max_duration: Duration,
+    curr_screen: usize,
+) -> Animator<Text> {
+    Animator::new(
+        Tween::new(
+            brand.ease_function,
+            Duration::from_secs(1),
+            SplashTextColorLens::new(
+                text.sections
+                    .iter()
+                    .map(|_| Color::WHITE.with_alpha(0.))
+                    .collect(),
+            ),
+        )
+        .then(
+            Delay::new(max_duration).then(
+                Tween::new(
+                    brand.ease_function,
+                    brand.duration,
+                    SplashTextColorLens::new(text.sections.iter().map(|s| s.style.color).collect())
+                        .with_animation(brand.text_animation),
+                )
+                .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+                .with_repeat_count(RepeatCount::Finite(2))
+                .with_completed_event(curr_screen as u64),
+            ),
+        ),
+    )
+}
+
+// Components of every brand entity, the one with the animator
+#[cfg_attr(not(feature = "audio"), allow(unused_variables))]
+pub(crate) fn link_brand(
+    brand_cmd: &mut EntityCommands,
+    assets: &AssetServer,
+    root: Entity,
+    brand: &SplashItem,
+    max_duration: Duration,
+) {
+    brand_cmd.insert(SplashParent(root));
+
+    #[cfg(feature = "audio")]
+    if let Some(audio) = &brand.audio {
+        brand_cmd.insert(crate::audio::SplashAudioCue::new(
+            audio,
+            assets,
+            Duration::from_secs(1) + max_duration,
+        ));
+    }
+}
+
+// Node containing the brands of a screen
+#[cfg(feature = "ui")]
+pub(crate) fn screen_style(splash_type: &SplashType, overrides: &SplashStyle) -> Style {
+    let (flex_direction, flex_wrap) = match splash_type {
+        SplashType::List | SplashType::Free => (FlexDirection::Column, FlexWrap::NoWrap),
+        SplashType::Grid => (FlexDirection::Row, FlexWrap::Wrap),
+    };
+    let mut style = Style {
+        flex_wrap,
+        flex_direction,
+        display: Display::Flex,
+        direction: Direction::LeftToRight,
+        align_items: AlignItems::Center,
+        align_content: AlignContent::Center,
+        justify_content: JustifyContent::Center,
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        overflow: Overflow::clip(),
+        ..default()
+    };
+    overrides.apply(&mut style);
+    style
+}
+
+// Size of the brand, anchored brands are out of the flex layout.
+// The style of the brand is applied last so it can replace any value
+#[cfg(feature = "ui")]
+pub(crate) fn brand_style(brand: &SplashItem, content: &Style, free: bool) -> Style {
+    let mut style = Style {
+        width: brand.width,
+        height: brand.height,
+        ..default()
+    };
+    match brand.asset {
+        SplashAssetType::SingleText(..) => {
+            style.flex_direction = content.flex_direction;
+            style.flex_wrap = content.flex_wrap;
+        }
+        // Image fitted inside the box of the brand
+        SplashAssetType::SingleImage(_) if brand.fit.clipped() => {
+            style.overflow = Overflow::clip();
+        }
+        // Sizes left to auto keep the aspect ratio of the image
+        SplashAssetType::SingleImage(_) => match brand.fit {
+            SplashImageFit::Native => {
+                style.width = Val::Auto;
+                style.height = Val::Auto;
             }
-        });
+            SplashImageFit::FitWidth => style.height = Val::Auto,
+            _ => {}
+        },
+        _ => {}
+    }
+    if let Some(anchor) = brand.anchor.or(free.then_some(SplashAnchor::Center)) {
+        anchor_style(&mut style, anchor, brand.anchor_offset);
     }
+    brand.style.apply(&mut style);
+    style
 }
 
-fn create_animator<C, L>(
+// Axis without inset are centered by the screen node
+#[cfg(feature = "ui")]
+fn anchor_style(style: &mut Style, anchor: SplashAnchor, (x, y): (Val, Val)) {
+    style.position_type = PositionType::Absolute;
+    match anchor {
+        SplashAnchor::TopLeft | SplashAnchor::Left | SplashAnchor::BottomLeft => style.left = x,
+        SplashAnchor::TopRight | SplashAnchor::Right | SplashAnchor::BottomRight => style.right = x,
+        SplashAnchor::Center | SplashAnchor::Top | SplashAnchor::Bottom => {}
+    }
+    match anchor {
+        SplashAnchor::TopLeft | SplashAnchor::Top | SplashAnchor::TopRight => style.top = y,
+        SplashAnchor::BottomLeft | SplashAnchor::Bottom | SplashAnchor::BottomRight => {
+            style.bottom = y
+        }
+        SplashAnchor::Center | SplashAnchor::Left | SplashAnchor::Right => {}
+    }
+}
+
+pub(crate) fn create_animator<C, L>(
     brand: &SplashItem,
     max_duration: Duration,
     curr_screen: usize,
@@ -198,3 +637,107 @@ where
         ),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SplashItem, SplashTransition};
+
+    fn screen(brands: usize, stagger: Duration, transition: SplashTransition) -> SplashScreen {
+        SplashScreen {
+            brands: vec![SplashItem::default(); brands],
+            stagger,
+            transition,
+            ..default()
+        }
+    }
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    #[test]
+    fn delays_after_end() {
+        let screens = SplashScreens(vec![
+            screen(1, Duration::ZERO, SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::None),
+        ]);
+        assert_eq!(get_screen_delays(&screens), [secs(1.), secs(3.), secs(5.)]);
+    }
+
+    #[test]
+    fn delays_with_crossfade() {
+        let screens = SplashScreens(vec![
+            screen(1, Duration::ZERO, SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::Crossfade(secs(0.5))),
+        ]);
+        assert_eq!(get_screen_delays(&screens), [secs(1.), secs(2.5)]);
+
+        // Not before the previous screen starts
+        let screens = SplashScreens(vec![
+            screen(1, Duration::ZERO, SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::Crossfade(secs(3.))),
+        ]);
+        assert_eq!(get_screen_delays(&screens), [secs(1.), secs(1.)]);
+
+        let mut first = screen(1, Duration::ZERO, SplashTransition::None);
+        first.wait_to_start = WaitScreenType::Specific(secs(2.));
+        let screens = SplashScreens(vec![
+            first,
+            screen(1, Duration::ZERO, SplashTransition::Crossfade(secs(2.))),
+        ]);
+        assert_eq!(get_screen_delays(&screens), [secs(1.), secs(1.)]);
+    }
+
+    #[test]
+    fn delays_with_stagger() {
+        let screens = SplashScreens(vec![
+            screen(3, secs(0.5), SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::None),
+            screen(1, Duration::ZERO, SplashTransition::Crossfade(secs(0.5))),
+        ]);
+        assert_eq!(get_screen_delays(&screens), [secs(1.), secs(4.), secs(5.5)]);
+    }
+
+    #[cfg(feature = "ui")]
+    #[test]
+    fn brand_style_applied_last() {
+        let brand = SplashItem {
+            asset: SplashAssetType::SingleText(Text::default(), String::new()),
+            anchor: Some(SplashAnchor::TopLeft),
+            style: SplashStyle::new(|style| {
+                style.position_type = PositionType::Relative;
+                style.flex_wrap = FlexWrap::WrapReverse;
+            }),
+            ..default()
+        };
+        let content = screen_style(&SplashType::Grid, &SplashStyle::default());
+        let style = brand_style(&brand, &content, false);
+        assert_eq!(style.position_type, PositionType::Relative);
+        assert_eq!(style.flex_wrap, FlexWrap::WrapReverse);
+        assert_eq!(style.flex_direction, FlexDirection::Row);
+
+        let brand = SplashItem {
+            fit: SplashImageFit::Cover,
+            style: SplashStyle::new(|style| style.overflow = Overflow::visible()),
+            ..default()
+        };
+        let style = brand_style(&brand, &content, true);
+        assert_eq!(style.overflow, Overflow::visible());
+        assert_eq!(style.position_type, PositionType::Absolute);
+    }
+
+    #[test]
+    fn delays_never_zero() {
+        let mut first = screen(1, Duration::ZERO, SplashTransition::None);
+        first.wait_to_start = WaitScreenType::Specific(Duration::ZERO);
+        let screens = SplashScreens(vec![
+            first,
+            screen(1, Duration::ZERO, SplashTransition::None),
+        ]);
+        assert!(get_screen_delays(&screens)
+            .iter()
+            .all(|delay| !delay.is_zero()));
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+type StylePatch = Arc<dyn Fn(&mut Style) + Send + Sync>;
+
+/// Change of the style of a screen or brand node, applied after every value
+/// set by the splash (sizes, anchors, flex and clipping) so any of them can be replaced
+#[derive(Clone, Default)]
+pub struct SplashStyle(Option<StylePatch>);
+
+impl SplashStyle {
+    /// Run `patch` on the style of the node, like `|style| style.row_gap = Val::Px(32.)`
+    pub fn new(patch: impl Fn(&mut Style) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(patch)))
+    }
+
+    pub(crate) fn apply(&self, style: &mut Style) {
+        if let Some(patch) = &self.0 {
+            patch(style);
+        }
+    }
+}
+
+impl fmt::Debug for SplashStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SplashStyle")
+            .field(&self.0.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
@@ -1,116 +1,468 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
 use bevy::{
     input::{gamepad::GamepadEvent, keyboard::KeyboardInput, mouse::MouseButtonInput},
     prelude::*,
-    state::state::{FreelyMutableState, NextState, States},
+    time::TimeUpdateStrategy,
 };
 use bevy_tweening::TweenCompleted;
 
-use crate::{SplashScreenSkipEvent, SplashScreenSkipable};
+use crate::{
+    background::screen_color,
+    condition::{mark_screen_seen, mark_started_seen, SplashSeen},
+    PlaySplash, SplashEndAction, SplashScreenEndEvent, SplashScreenSkipEvent, SplashScreens,
+};
+#[cfg(feature = "ui")]
+use crate::{background::set_background_image, SplashImageFit};
 // Internal components for system logic
 #[derive(Component)]
 pub struct ClearSplash;
 
+// Splash started by the plugin state
 #[derive(Resource)]
-pub(crate) struct MaxScreens<S>(pub(crate) u64, pub(crate) S, pub(crate) u64)
-where
-    S: States;
+pub(crate) struct SplashIntro(pub(crate) PlaySplash);
 
 #[derive(Component)]
 pub(crate) struct SplashBackground {
     pub(super) screens: Vec<u64>,
-    pub(super) screen_colors: Vec<Color>,
+    pub(crate) screen_colors: Vec<Color>,
+    #[cfg(feature = "ui")]
+    pub(crate) screen_images: Vec<Option<(Handle<Image>, SplashImageFit)>>,
+    // node showing `screen_images`
+    #[cfg(feature = "ui")]
+    pub(crate) image: Option<Entity>,
+}
+
+impl SplashBackground {
+    pub(crate) fn new(screens: &SplashScreens) -> Self {
+        Self {
+            screens: screens
+                .0
+                .iter()
+                .map(|s| s.brands.len() as u64 * 2)
+                .collect(),
+            screen_colors: screens.0.iter().map(screen_color).collect(),
+            #[cfg(feature = "ui")]
+            screen_images: Vec::new(),
+            #[cfg(feature = "ui")]
+            image: None,
+        }
+    }
 }
 
+// Root of a running splash, the nodes are spawned by `create_splash`
+#[derive(Component)]
+pub(crate) struct SplashSequence {
+    pub(crate) screens: SplashScreens,
+    // screens given by the user, before `filter_splash`, `loop_from` indexes them
+    source: SplashScreens,
+    pub(crate) skipable: bool,
+    // only skipped by `SplashScreenSkipEvent`
+    ignore_default_events: bool,
+    // remove all `ClearSplash` nodes, not only this sequence, other sequences are kept
+    pub(crate) clear_all: bool,
+    pub(crate) on_end: Option<SplashEndAction>,
+    pub(crate) loop_from: Option<usize>,
+    #[cfg(feature = "ui")]
+    pub(crate) safe_area: UiRect,
+    #[cfg(feature = "ui")]
+    pub(crate) render: crate::SplashRender,
+    pub(crate) camera: crate::SplashCamera,
+    // spawned by `SplashCamera::Spawn`
+    pub(crate) owned_camera: Option<Entity>,
+    #[cfg(feature = "capture")]
+    pub(crate) capture: Option<crate::SplashCapture>,
+    pub(crate) fixed_step: Option<Duration>,
+    #[cfg(feature = "audio")]
+    pub(crate) music: Option<crate::SplashMusic>,
+    pub(crate) elapsed: Duration,
+    // screen which background is shown
+    pub(crate) screen: usize,
+    screens_end: u64,
+    // screen => count of brand show
+    brands_showed: HashMap<u64, u64>,
+}
+
+impl From<PlaySplash> for SplashSequence {
+    fn from(splash: PlaySplash) -> Self {
+        Self {
+            screens: SplashScreens(splash.screens.clone()),
+            source: SplashScreens(splash.screens),
+            skipable: splash.skipable,
+            ignore_default_events: splash.ignore_default_events,
+            clear_all: false,
+            on_end: splash.on_end,
+            loop_from: splash.loop_from,
+            #[cfg(feature = "ui")]
+            safe_area: splash.safe_area,
+            #[cfg(feature = "ui")]
+            render: splash.render,
+            camera: splash.camera,
+            owned_camera: None,
+            #[cfg(feature = "capture")]
+            capture: splash.capture,
+            fixed_step: splash.fixed_step,
+            #[cfg(feature = "audio")]
+            music: splash.music,
+            elapsed: Duration::ZERO,
+            screen: 0,
+            screens_end: 0,
+            brands_showed: HashMap::new(),
+        }
+    }
+}
+
+impl SplashSequence {
+    fn max_screens(&self) -> u64 {
+        self.screens.0.len() as u64 - 1
+    }
+
+    fn max_events(&self) -> u64 {
+        (self.screens.0.iter().map(|s| s.brands.len()).sum::<usize>() * 2) as u64
+    }
+}
+
+// Sequence which owns an animated brand
+#[derive(Component)]
+pub(crate) struct SplashParent(pub(crate) Entity);
+
 //
 // Remove all nodes when splash end
 //
-pub(crate) fn splash_end<'a, S: FreelyMutableState>(
+pub(crate) fn splash_end<'a>(
+    cmd: &mut Commands,
+    end: &mut EventWriter<SplashScreenEndEvent>,
+    (entity, sequence): (Entity, &SplashSequence),
+    skipped: bool,
+    brands: impl Iterator<Item = (Entity, &'a ClearSplash)>,
+) {
+    if sequence.clear_all {
+        for (brand, _) in brands {
+            cmd.entity(brand).despawn_recursive();
+        }
+    }
+    cmd.entity(entity).despawn_recursive();
+    if let Some(camera) = sequence.owned_camera {
+        cmd.entity(camera).despawn_recursive();
+    }
+    if let Some(SplashEndAction(action)) = sequence.on_end.clone() {
+        cmd.add(move |world: &mut World| action(world));
+    }
+    end.send(SplashScreenEndEvent { entity, skipped });
+}
+
+//
+// Start again the looped screens, nodes are created again by `create_splash`
+//
+fn splash_restart(cmd: &mut Commands, entity: Entity, sequence: &mut SplashSequence) {
+    let from = sequence.loop_from.unwrap_or_default();
+    let source = &sequence.source.0;
+    // Filtered again by `filter_splash`
+    sequence.screens = SplashScreens(source[from.min(source.len() - 1)..].to_vec());
+    sequence.elapsed = Duration::ZERO;
+    sequence.screen = 0;
+    sequence.screens_end = 0;
+    sequence.brands_showed.clear();
+    cmd.entity(entity)
+        .despawn_descendants()
+        .remove::<SplashBackground>();
+}
+
+//
+// Start splash sequences requested by event
+//
+pub(crate) fn play_splash_event(mut cmd: Commands, mut reader: EventReader<PlaySplash>) {
+    for e in reader.read() {
+        cmd.spawn(SplashSequence::from(e.clone()));
+    }
+}
+
+//
+// Start the splash of plugin state
+//
+pub(crate) fn create_intro(mut cmd: Commands, intro: Res<SplashIntro>) {
+    let mut sequence = SplashSequence::from(intro.0.clone());
+    sequence.clear_all = true;
+    cmd.spawn(sequence);
+}
+
+// Step driving the time while fixed step sequences play, and the strategy
+// of the app restored after them
+#[derive(Resource)]
+pub(crate) struct SplashFixedStep {
+    step: Duration,
+    previous: TimeUpdateStrategy,
+}
+
+//
+// Advance the time by the step of fixed step sequences, before it is updated.
+// Sequences with and without a step never play together, see `create_splash`
+//
+pub(crate) fn fixed_step_time(
     mut cmd: Commands,
-    next_state: S,
-    brands: impl Iterator<Item = (Entity, &'a Node, &'a ClearSplash)>,
+    mut strategy: ResMut<TimeUpdateStrategy>,
+    fixed: Option<ResMut<SplashFixedStep>>,
+    sequences: Query<(&SplashSequence, Has<SplashBackground>)>,
 ) {
-    for (entity, _, _) in brands {
-        cmd.entity(entity).despawn_recursive();
+    let mut steps = sequences.iter().filter_map(|(s, _)| s.fixed_step);
+    // Sequences on the time of the app finish before the step is applied
+    let real_time = sequences
+        .iter()
+        .any(|(s, built)| built && s.fixed_step.is_none());
+    match (fixed, steps.next()) {
+        (None, Some(step)) if !real_time => {
+            let previous =
+                std::mem::replace(&mut *strategy, TimeUpdateStrategy::ManualDuration(step));
+            cmd.insert_resource(SplashFixedStep { step, previous });
+        }
+        // Back to the time of the app when the sequences end
+        (Some(mut fixed), None) => {
+            *strategy = std::mem::take(&mut fixed.previous);
+            cmd.remove_resource::<SplashFixedStep>();
+        }
+        (Some(fixed), Some(step)) => {
+            if step != fixed.step || steps.any(|s| s != fixed.step) {
+                warn_once!(
+                    "Splash sequences playing together use the fixed step of the first one, {:?}",
+                    fixed.step
+                );
+            }
+        }
+        (None, _) => {}
     }
-    cmd.insert_resource(NextState::Pending(next_state));
 }
 
 //
 // Logic to end splash and change background color
 //
-pub(crate) fn update_splash<S: FreelyMutableState>(
-    cmd: Commands,
-    brands: Query<(Entity, &Node, &ClearSplash)>,
-    mut background: Query<(&Node, &mut BackgroundColor, &SplashBackground)>,
+pub(crate) fn update_splash(
+    mut cmd: Commands,
+    brands: Query<(Entity, &ClearSplash), Without<SplashSequence>>,
+    parents: Query<&SplashParent>,
+    mut sequences: Query<(&mut SplashSequence, &SplashBackground)>,
+    #[cfg(feature = "ui")] mut colors: Query<&mut BackgroundColor>,
     mut reader: EventReader<TweenCompleted>,
-    max_screens: Res<MaxScreens<S>>,
-    mut screens_end: Local<u64>,
-    // screen => count of brand show
-    mut brands_showed: Local<HashMap<u64, u64>>,
+    mut end: EventWriter<SplashScreenEndEvent>,
+    mut seen: Option<ResMut<SplashSeen>>,
+    time: Res<Time>,
 ) {
-    let mut clear = false;
+    for (mut sequence, _) in sequences.iter_mut() {
+        sequence.elapsed += time.delta();
+    }
+
     for e in reader.read() {
-        *screens_end += 1;
-        clear = e.user_data == max_screens.0 && *screens_end == max_screens.2;
-        _ = brands_showed
+        let Ok(SplashParent(root)) = parents.get(e.entity) else {
+            continue;
+        };
+        let Ok((mut sequence, data)) = sequences.get_mut(*root) else {
+            continue;
+        };
+
+        sequence.screens_end += 1;
+        let showed = *sequence
+            .brands_showed
             .entry(e.user_data)
             .and_modify(|v| {
                 *v += 1;
             })
             .or_insert(1);
 
-        for (_, mut bg, data) in background.iter_mut() {
-            if let Some(v) = brands_showed.get(&e.user_data) {
-                if data.screens[e.user_data as usize] == *v
-                    && e.user_data as usize + 1 < data.screen_colors.len()
-                {
-                    bg.0 = data.screen_colors[e.user_data as usize + 1];
+        let next = e.user_data as usize + 1;
+        if data.screens[e.user_data as usize] == showed {
+            // Only screens fully shown count as seen
+            if let Some(seen) = seen.as_deref_mut() {
+                mark_screen_seen(&sequence.screens.0[e.user_data as usize], seen);
+            }
+            if next < data.screen_colors.len() {
+                // World backgrounds follow the screen of the sequence
+                sequence.screen = next;
+                #[cfg(feature = "ui")]
+                if let Ok(mut bg) = colors.get_mut(*root) {
+                    bg.0 = data.screen_colors[next];
+                }
+                #[cfg(feature = "ui")]
+                if let Some(image) = data.image {
+                    set_background_image(&mut cmd, image, data.screen_images[next].as_ref());
                 }
             }
         }
-    }
-    if clear {
-        splash_end(cmd, max_screens.1.clone(), brands.iter());
+
+        if e.user_data == sequence.max_screens() && sequence.screens_end == sequence.max_events() {
+            if sequence.loop_from.is_some() {
+                splash_restart(&mut cmd, *root, &mut sequence);
+            } else {
+                splash_end(&mut cmd, &mut end, (*root, &sequence), false, brands.iter());
+            }
+        }
     }
 }
 
 //
 // System for skip splash
 //
-pub(crate) fn splash_skip<S: FreelyMutableState>(
-    cmd: Commands,
+pub(crate) fn splash_skip(
+    mut cmd: Commands,
     mut kbd: EventReader<KeyboardInput>,
     mut mouse: EventReader<MouseButtonInput>,
     mut gamepad: EventReader<GamepadEvent>,
     mut touch: EventReader<TouchInput>,
-    dev_skip: EventReader<SplashScreenSkipEvent>,
-    brands: Query<(Entity, &Node, &ClearSplash)>,
-    max_screens: Res<MaxScreens<S>>,
-    skipable: Res<SplashScreenSkipable>,
+    mut dev_skip: EventReader<SplashScreenSkipEvent>,
+    brands: Query<(Entity, &ClearSplash), Without<SplashSequence>>,
+    sequences: Query<(Entity, Ref<SplashSequence>)>,
+    mut end: EventWriter<SplashScreenEndEvent>,
+    mut seen: Option<ResMut<SplashSeen>>,
 ) {
-    if brands.is_empty() || !skipable.0 {
+    use bevy::input::{touch::TouchPhase, ButtonState};
+
+    let skipped = !dev_skip.is_empty();
+    dev_skip.clear();
+
+    // Read the presses every frame, old ones would skip the next sequence at once
+    let pressed = kbd
+        .read()
+        .filter(|ev| ev.state == ButtonState::Pressed)
+        .count()
+        + mouse
+            .read()
+            .filter(|ev| ev.state == ButtonState::Pressed)
+            .count()
+        + touch
+            .read()
+            .filter(|ev| ev.phase == TouchPhase::Started)
+            .count()
+        + gamepad
+            .read()
+            .filter(|ev| matches!(ev, GamepadEvent::Button(_)))
+            .count();
+    if !skipped && pressed == 0 {
         return;
     }
+    // The press that started a sequence does not skip it
+    for (entity, sequence) in sequences
+        .iter()
+        .filter(|(_, s)| s.skipable && !s.is_added() && (skipped || !s.ignore_default_events))
+    {
+        if let Some(seen) = seen.as_deref_mut() {
+            mark_started_seen(&sequence, seen);
+        }
+        splash_end(&mut cmd, &mut end, (entity, &sequence), true, brands.iter());
+    }
+}
 
-    let mut done = false;
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::ecs::world::CommandQueue;
 
-    if !skipable.1 {
-        use bevy::input::{touch::TouchPhase, ButtonState};
+    use super::*;
+    use crate::{SplashScreen, SplashSequencePlugin};
 
-        done = kbd.read().any(|ev| ev.state == ButtonState::Pressed)
-            || mouse.read().any(|ev| ev.state == ButtonState::Pressed)
-            || touch.read().any(|ev| ev.phase == TouchPhase::Started);
+    // Screens told apart by their stagger, in seconds
+    fn restarted(loop_from: usize) -> (Vec<u64>, SplashSequence) {
+        let screens = (0..3)
+            .map(|i| SplashScreen {
+                stagger: Duration::from_secs(i),
+                ..default()
+            })
+            .collect();
+        let mut sequence = SplashSequence::from(PlaySplash {
+            screens,
+            loop_from: Some(loop_from),
+            ..default()
+        });
+        // First screen hidden by its `show`
+        sequence.screens.0.remove(0);
+        sequence.elapsed = Duration::from_secs(5);
 
-        for ev in gamepad.read() {
-            if let GamepadEvent::Button(_) = ev {
-                done = true;
-            }
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let mut queue = CommandQueue::default();
+        splash_restart(
+            &mut Commands::new(&mut queue, &world),
+            entity,
+            &mut sequence,
+        );
+        let staggers = sequence
+            .screens
+            .0
+            .iter()
+            .map(|s| s.stagger.as_secs())
+            .collect();
+        (staggers, sequence)
+    }
+
+    #[test]
+    fn restart_on_given_screens() {
+        let (staggers, sequence) = restarted(1);
+        assert_eq!(staggers, [1, 2]);
+        assert_eq!(sequence.loop_from, Some(1));
+        assert_eq!(sequence.elapsed, Duration::ZERO);
+
+        let (staggers, _) = restarted(0);
+        assert_eq!(staggers, [0, 1, 2]);
+    }
+
+    #[test]
+    fn restart_past_the_end() {
+        let (staggers, sequence) = restarted(10);
+        assert_eq!(staggers, [2]);
+        assert_eq!(sequence.loop_from, Some(10));
+    }
+
+    fn playing(world: &mut World, fixed_step: Option<Duration>, built: bool) -> Entity {
+        let sequence = SplashSequence::from(PlaySplash {
+            screens: vec![SplashScreen::default()],
+            fixed_step,
+            ..default()
+        });
+        let background = SplashBackground::new(&sequence.screens);
+        let mut entity = world.spawn(sequence);
+        if built {
+            entity.insert(background);
+        }
+        entity.id()
+    }
+
+    fn manual_step(world: &World) -> Option<Duration> {
+        match world.resource::<TimeUpdateStrategy>() {
+            TimeUpdateStrategy::ManualDuration(step) => Some(*step),
+            _ => None,
         }
     }
 
-    if done || !dev_skip.is_empty() {
-        splash_end(cmd, max_screens.1.clone(), brands.iter());
+    #[test]
+    fn fixed_step_waits_for_real_time() {
+        let step = Duration::from_millis(20);
+        let mut world = World::new();
+        world.init_resource::<TimeUpdateStrategy>();
+        let real = playing(&mut world, None, true);
+        let fixed = playing(&mut world, Some(step), false);
+
+        // Not applied to the sequence already on the time of the app
+        world.run_system_once(fixed_step_time);
+        assert!(!world.contains_resource::<SplashFixedStep>());
+        assert_eq!(manual_step(&world), None);
+
+        world.despawn(real);
+        world.run_system_once(fixed_step_time);
+        assert!(world.contains_resource::<SplashFixedStep>());
+        assert_eq!(manual_step(&world), Some(step));
+
+        // Restored once the fixed sequences end
+        world.despawn(fixed);
+        world.run_system_once(fixed_step_time);
+        assert!(!world.contains_resource::<SplashFixedStep>());
+        assert_eq!(manual_step(&world), None);
+    }
+
+    #[test]
+    fn schedule_orders_animators() {
+        let mut app = App::new();
+        app.add_plugins(SplashSequencePlugin);
+        // Built without cycles between the animators and the sequence systems
+        let world = app.world_mut();
+        let mut update = world.resource_mut::<Schedules>().remove(Update).unwrap();
+        update.initialize(world).unwrap();
     }
 }
@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+#[cfg(feature = "ui")]
+use crate::systems::SplashSequence;
+
+/// How a screen enters and leaves, the whole screen is animated.
+/// World splashes only use the overlap of the crossfade
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SplashTransition {
+    /// Only the brands fade
+    #[default]
+    None,
+    /// Start while the previous screen fades out, covering it with this
+    /// screen faded in over the duration
+    Crossfade(Duration),
+    /// Enter from the right and leave to the left
+    SlideLeft(Duration),
+    /// Enter from the left and leave to the right
+    SlideRight(Duration),
+    /// Enter from the bottom and leave to the top
+    SlideUp(Duration),
+    /// Open a box from the center and close back to it
+    BoxWipe(Duration),
+    /// Fade from the color when starting, and to the color and back when ending
+    DipToColor(Color, Duration),
+}
+
+impl SplashTransition {
+    // Time the screen starts before the previous one ends
+    pub(crate) fn overlap(&self) -> Duration {
+        match self {
+            SplashTransition::Crossfade(duration) => *duration,
+            _ => Duration::ZERO,
+        }
+    }
+
+    // Needs `animate_transitions` to move the screen node
+    #[cfg(feature = "ui")]
+    pub(crate) fn animated(&self) -> bool {
+        !matches!(self, SplashTransition::None)
+    }
+
+    #[cfg(feature = "ui")]
+    fn duration(&self) -> Duration {
+        match self {
+            SplashTransition::None => Duration::ZERO,
+            SplashTransition::Crossfade(duration)
+            | SplashTransition::SlideLeft(duration)
+            | SplashTransition::SlideRight(duration)
+            | SplashTransition::SlideUp(duration)
+            | SplashTransition::BoxWipe(duration)
+            | SplashTransition::DipToColor(_, duration) => *duration,
+        }
+    }
+}
+
+// Screen node and when its brands are shown
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct SplashScreenTransition {
+    pub(crate) root: Entity,
+    pub(crate) transition: SplashTransition,
+    pub(crate) start: Duration,
+    pub(crate) end: Duration,
+    // Stage node kept at full size by the box wipe, overlay of the dip or
+    // underlay of the crossfade
+    pub(crate) target: Entity,
+    // Color of the crossfade underlay
+    pub(crate) color: Color,
+}
+
+#[cfg(feature = "ui")]
+impl SplashScreenTransition {
+    // `0` hidden to `1` fully shown
+    fn shown(&self, elapsed: Duration) -> f32 {
+        let duration = self.transition.duration().as_secs_f32().max(f32::EPSILON);
+        let fade_in = elapsed.saturating_sub(self.start).as_secs_f32() / duration;
+        let fade_out = self.end.saturating_sub(elapsed).as_secs_f32() / duration;
+        fade_in.min(fade_out).clamp(0., 1.)
+    }
+
+    fn entering(&self, elapsed: Duration) -> bool {
+        elapsed < self.start + self.end.saturating_sub(self.start) / 2
+    }
+
+    // `1` when ended to `0` once the transition length passed
+    fn leaving(&self, elapsed: Duration) -> f32 {
+        let duration = self.transition.duration().as_secs_f32().max(f32::EPSILON);
+        1. - (elapsed.saturating_sub(self.end).as_secs_f32() / duration).min(1.)
+    }
+}
+
+#[cfg(feature = "ui")]
+fn set_alpha(background: &mut Mut<BackgroundColor>, color: Color, alpha: f32) {
+    let color = color.with_alpha(color.alpha() * alpha);
+    if background.0 != color {
+        background.0 = color;
+    }
+}
+
+#[cfg(feature = "ui")]
+fn set_rect(style: &mut Mut<Style>, left: Val, top: Val, width: Val, height: Val) {
+    if style.left != left || style.top != top || style.width != width || style.height != height {
+        style.left = left;
+        style.top = top;
+        style.width = width;
+        style.height = height;
+    }
+}
+
+//
+// Move the screens entering or leaving
+//
+#[cfg(feature = "ui")]
+pub(crate) fn animate_transitions(
+    sequences: Query<&SplashSequence>,
+    nodes: Query<&Node>,
+    screens: Query<(Entity, &SplashScreenTransition, &Parent)>,
+    mut styles: Query<&mut Style>,
+    mut colors: Query<&mut BackgroundColor>,
+) {
+    for (entity, screen, parent) in screens.iter() {
+        let Ok(sequence) = sequences.get(screen.root) else {
+            continue;
+        };
+        let elapsed = sequence.elapsed;
+        let hidden = 1. - screen.shown(elapsed);
+        let offset = if screen.entering(elapsed) {
+            hidden * 100.
+        } else {
+            -hidden * 100.
+        };
+        let full = Val::Percent(100.);
+
+        match screen.transition {
+            SplashTransition::SlideLeft(_) => {
+                if let Ok(mut style) = styles.get_mut(entity) {
+                    set_rect(&mut style, Val::Percent(offset), Val::ZERO, full, full);
+                }
+            }
+            SplashTransition::SlideRight(_) => {
+                if let Ok(mut style) = styles.get_mut(entity) {
+                    set_rect(&mut style, Val::Percent(-offset), Val::ZERO, full, full);
+                }
+            }
+            SplashTransition::SlideUp(_) => {
+                if let Ok(mut style) = styles.get_mut(entity) {
+                    set_rect(&mut style, Val::ZERO, Val::Percent(offset), full, full);
+                }
+            }
+            SplashTransition::BoxWipe(_) => {
+                let Ok(area) = nodes.get(parent.get()).map(Node::size) else {
+                    continue;
+                };
+                let size = area * (1. - hidden);
+                let corner = (area - size) / 2.;
+                if let Ok(mut style) = styles.get_mut(entity) {
+                    set_rect(
+                        &mut style,
+                        Val::Px(corner.x),
+                        Val::Px(corner.y),
+                        Val::Px(size.x),
+                        Val::Px(size.y),
+                    );
+                }
+                // The stage stays still while the screen is clipped
+                if let Ok(mut style) = styles.get_mut(screen.target) {
+                    set_rect(
+                        &mut style,
+                        Val::Px(-corner.x),
+                        Val::Px(-corner.y),
+                        Val::Px(area.x),
+                        Val::Px(area.y),
+                    );
+                }
+            }
+            SplashTransition::DipToColor(color, _) => {
+                let alpha = if elapsed < screen.start {
+                    0.
+                } else if elapsed <= screen.end {
+                    hidden
+                } else {
+                    screen.leaving(elapsed)
+                };
+                if let Ok(mut background) = colors.get_mut(screen.target) {
+                    set_alpha(&mut background, color, alpha);
+                }
+            }
+            // The underlay covers the previous screen, then leaves the background visible
+            SplashTransition::Crossfade(_) => {
+                let alpha = if elapsed >= screen.start && screen.entering(elapsed) && hidden > 0. {
+                    1. - hidden
+                } else {
+                    0.
+                };
+                if let Ok(mut background) = colors.get_mut(screen.target) {
+                    set_alpha(&mut background, screen.color, alpha);
+                }
+            }
+            SplashTransition::None => {}
+        }
+    }
+}
@@ -0,0 +1,489 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::window::PrimaryWindow;
+
+use crate::{
+    animation::SplashFrames,
+    background::screen_color,
+    splash::{
+        brand_text, create_animator, create_text_animator, get_brand_start, get_screen_delays,
+        link_brand,
+    },
+    systems::{ClearSplash, SplashBackground, SplashSequence},
+    SplashAnchor, SplashAssetType, SplashItem, SplashScreen, SplashScreens, SplashSpriteColorLens,
+    SplashType,
+};
+
+/// Space between brands of a list or grid without `gap` on the style
+const WORLD_GAP: f32 = 16.;
+
+// Window size used by world splashes without a primary window
+const WORLD_WINDOW: Vec2 = Vec2::new(1280., 720.);
+
+/// How the splash is drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplashRender {
+    /// `bevy_ui` nodes
+    #[cfg(feature = "ui")]
+    #[default]
+    Ui,
+    /// Sprites and 2d text centered on the world origin, laid out with the
+    /// window size. Only uses background colors, without transitions,
+    /// safe area or breakpoints
+    #[cfg_attr(not(feature = "ui"), default)]
+    World,
+}
+
+/// Size of a brand without `bevy_ui`, resolved like its `Val`
+#[cfg(not(feature = "ui"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Val {
+    #[default]
+    Auto,
+    Px(f32),
+    /// Of the window size on the same axis
+    Percent(f32),
+    Vw(f32),
+    Vh(f32),
+    VMin(f32),
+    VMax(f32),
+}
+
+#[cfg(not(feature = "ui"))]
+impl Val {
+    pub const ZERO: Self = Self::Px(0.);
+}
+
+/// Color of a screen without `bevy_ui`, like its `BackgroundColor`
+#[cfg(not(feature = "ui"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackgroundColor(pub Color);
+
+#[cfg(not(feature = "ui"))]
+impl Default for BackgroundColor {
+    fn default() -> Self {
+        Self(Color::NONE)
+    }
+}
+
+// Pixels of `val` on an axis `parent` long, `None` when left to auto
+fn resolve(val: Val, parent: f32, window: Vec2) -> Option<f32> {
+    #[cfg(feature = "ui")]
+    return val.resolve(parent, window).ok();
+    #[cfg(not(feature = "ui"))]
+    match val {
+        Val::Auto => None,
+        Val::Px(value) => Some(value),
+        Val::Percent(value) => Some(parent * value / 100.),
+        Val::Vw(value) => Some(window.x * value / 100.),
+        Val::Vh(value) => Some(window.y * value / 100.),
+        Val::VMin(value) => Some(window.min_element() * value / 100.),
+        Val::VMax(value) => Some(window.max_element() * value / 100.),
+    }
+}
+
+// Size the world splash is laid out on, the one of the frames while capturing
+#[cfg_attr(not(feature = "capture"), allow(unused_variables))]
+pub(crate) fn world_size(sequence: &SplashSequence, window: Option<&Window>) -> Vec2 {
+    #[cfg(feature = "capture")]
+    if let Some(capture) = &sequence.capture {
+        return capture.size.as_vec2();
+    }
+    window.map_or(WORLD_WINDOW, Window::size)
+}
+
+// Sprite following the background color of the splash
+#[derive(Component)]
+pub(crate) struct SplashWorldBackground;
+
+// Screen of a world splash, its brands are placed by `layout_world_brands`
+#[derive(Component)]
+pub(crate) struct SplashWorldScreen(SplashScreen);
+
+// Sizes of a world brand, the axis left to auto take the size of its image or text
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SplashWorldBrand {
+    width: Val,
+    height: Val,
+}
+
+impl SplashWorldBrand {
+    fn new(brand: &SplashItem) -> Self {
+        Self {
+            width: brand.width,
+            height: brand.height,
+        }
+    }
+
+    // Size in pixels on `window`, with the axis left to auto taken from `natural`,
+    // `None` until it is known
+    fn size(&self, window: Vec2, natural: Option<Vec2>) -> Option<Vec2> {
+        let width = resolve(self.width, window.x, window);
+        let height = resolve(self.height, window.y, window);
+        match (width, height) {
+            (Some(width), Some(height)) => Some(Vec2::new(width, height)),
+            (width, height) => {
+                natural.map(|n| Vec2::new(width.unwrap_or(n.x), height.unwrap_or(n.y)))
+            }
+        }
+    }
+}
+
+pub(crate) fn create_world_splash(
+    cmd: &mut Commands,
+    assets: &AssetServer,
+    layouts: &mut Assets<TextureAtlasLayout>,
+    size: Vec2,
+    root: Entity,
+    screens: &SplashScreens,
+) {
+    let delays = get_screen_delays(screens);
+
+    cmd.entity(root)
+        .insert(SpatialBundle::default())
+        .insert(ClearSplash)
+        .with_children(|cmd| {
+            cmd.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: screen_color(&screens.0[0]),
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    ..default()
+                },
+                SplashWorldBackground,
+            ));
+
+            for (i_screen, screen) in screens.0.iter().enumerate() {
+                cmd.spawn((
+                    SpatialBundle::from_transform(Transform::from_xyz(
+                        0.,
+                        0.,
+                        1. + i_screen as f32,
+                    )),
+                    SplashWorldScreen(screen.clone()),
+                ))
+                .with_children(|cmd| {
+                    for (i_brand, brand) in screen.brands.iter().enumerate() {
+                        create_world_brand(
+                            cmd,
+                            assets,
+                            layouts,
+                            root,
+                            brand,
+                            i_screen,
+                            delays[i_screen] + get_brand_start(screen, i_brand),
+                            size,
+                        );
+                    }
+                });
+            }
+        });
+}
+
+// Center of each brand of `sizes`, lists go from top to bottom and grids from left to right
+fn world_layout(screen: &SplashScreen, window: Vec2, sizes: &[Vec2]) -> Vec<Vec2> {
+    // Gaps left to auto by the style of the screen use the default
+    #[cfg(feature = "ui")]
+    let (row_gap, column_gap) = {
+        let mut style = Style {
+            row_gap: Val::Auto,
+            column_gap: Val::Auto,
+            ..default()
+        };
+        screen.style.apply(&mut style);
+        (style.row_gap, style.column_gap)
+    };
+    #[cfg(not(feature = "ui"))]
+    let (row_gap, column_gap) = (Val::Auto, Val::Auto);
+    let gap = |val: Val, size: f32| resolve(val, size, window).unwrap_or(WORLD_GAP);
+    let (axis, gap) = match screen.splash_type {
+        SplashType::Grid => (Vec2::X, gap(column_gap, window.x)),
+        SplashType::List | SplashType::Free => (Vec2::NEG_Y, gap(row_gap, window.y)),
+    };
+
+    // Brands in the flow, the anchored ones are placed by their anchor
+    let anchor = |b: &SplashItem| {
+        b.anchor
+            .or((screen.splash_type == SplashType::Free).then_some(SplashAnchor::Center))
+    };
+    let length = screen
+        .brands
+        .iter()
+        .zip(sizes)
+        .filter(|(brand, _)| anchor(brand).is_none())
+        .map(|(_, size)| (*size * axis).length() + gap)
+        .sum::<f32>()
+        - gap;
+    let mut cursor = -length / 2.;
+
+    screen
+        .brands
+        .iter()
+        .zip(sizes)
+        .map(|(brand, size)| {
+            let Some(anchor) = anchor(brand) else {
+                let half = (*size * axis).length() / 2.;
+                let center = axis * (cursor + half);
+                cursor += half * 2. + gap;
+                return center;
+            };
+            let (x, y) = brand.anchor_offset;
+            let offset = Vec2::new(
+                resolve(x, window.x, window).unwrap_or_default(),
+                resolve(y, window.y, window).unwrap_or_default(),
+            );
+            let edge = (window - *size) / 2. - offset;
+            let side = match anchor {
+                SplashAnchor::Center => Vec2::ZERO,
+                SplashAnchor::TopLeft => Vec2::new(-1., 1.),
+                SplashAnchor::Top => Vec2::Y,
+                SplashAnchor::TopRight => Vec2::ONE,
+                SplashAnchor::Left => Vec2::NEG_X,
+                SplashAnchor::Right => Vec2::X,
+                SplashAnchor::BottomLeft => Vec2::NEG_ONE,
+                SplashAnchor::Bottom => Vec2::NEG_Y,
+                SplashAnchor::BottomRight => Vec2::new(1., -1.),
+            };
+            side * edge
+        })
+        .collect()
+}
+
+fn create_world_brand(
+    cmd: &mut ChildBuilder,
+    assets: &AssetServer,
+    layouts: &mut Assets<TextureAtlasLayout>,
+    root: Entity,
+    brand: &SplashItem,
+    i_screen: usize,
+    max_duration: Duration,
+    window: Vec2,
+) {
+    // Placed by `layout_world_brands`
+    let size = SplashWorldBrand::new(brand);
+    let transform = Transform::default();
+    let sprite = |texture: Handle<Image>| SpriteBundle {
+        sprite: Sprite {
+            custom_size: size.size(window, None),
+            ..default()
+        },
+        texture,
+        transform,
+        ..default()
+    };
+    let frames = |frames: usize, fps: f32, mode| SplashFrames {
+        frames,
+        fps,
+        mode,
+        delay: Duration::from_secs(1) + max_duration,
+        elapsed: Duration::ZERO,
+    };
+
+    let mut brand_cmd = match &brand.asset {
+        SplashAssetType::SingleText(text, font) => {
+            let text = brand_text(brand, text, font, assets);
+            cmd.spawn((
+                Text2dBundle {
+                    text: text.clone(),
+                    transform,
+                    ..default()
+                },
+                create_text_animator(brand, &text, max_duration, i_screen),
+            ))
+        }
+        SplashAssetType::SingleImage(handler) => cmd.spawn((
+            sprite(assets.load(handler)),
+            create_animator::<Sprite, SplashSpriteColorLens>(brand, max_duration, i_screen),
+        )),
+        SplashAssetType::SpriteSheet(handler, sheet) => cmd.spawn((
+            sprite(assets.load(handler)),
+            TextureAtlas {
+                layout: layouts.add(sheet.layout()),
+                index: 0,
+            },
+            frames((sheet.columns * sheet.rows) as usize, sheet.fps, sheet.mode),
+            create_animator::<Sprite, SplashSpriteColorLens>(brand, max_duration, i_screen),
+        )),
+        SplashAssetType::ImageSequence(sequence) => {
+            let images = sequence.load(assets);
+            cmd.spawn((
+                sprite(images.frames.first().cloned().unwrap_or_default()),
+                frames(images.frames.len(), sequence.fps, sequence.mode),
+                images,
+                create_animator::<Sprite, SplashSpriteColorLens>(brand, max_duration, i_screen),
+            ))
+        }
+        // The model is drawn over the window, the sprite only times it
+        #[cfg(feature = "scene")]
+        SplashAssetType::Scene(scene) => cmd.spawn((
+            sprite(Handle::default()),
+            create_animator::<Sprite, SplashSpriteColorLens>(
+                &crate::scene::placeholder_brand(brand, false),
+                max_duration,
+                i_screen,
+            ),
+            crate::scene::SplashSceneBrand::new(scene, brand, max_duration),
+        )),
+    };
+    brand_cmd.insert(size);
+    link_brand(&mut brand_cmd, assets, root, brand, max_duration);
+}
+
+type WorldBrandQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static SplashWorldBrand,
+        &'static mut Transform,
+        Option<&'static mut Sprite>,
+        Option<&'static Handle<Image>>,
+        Option<&'static TextureAtlas>,
+        Option<&'static TextLayoutInfo>,
+    ),
+>;
+
+//
+// Place the brands of world screens with the sizes of their images and texts
+//
+pub(crate) fn layout_world_brands(
+    images: Res<Assets<Image>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    sequences: Query<&SplashSequence>,
+    screens: Query<(&SplashWorldScreen, &Parent, &Children)>,
+    mut brands: WorldBrandQuery,
+) {
+    let window = windows.get_single().ok();
+    for (SplashWorldScreen(screen), parent, children) in screens.iter() {
+        let Ok(sequence) = sequences.get(parent.get()) else {
+            continue;
+        };
+        let size = world_size(sequence, window);
+        let sizes = children
+            .iter()
+            .filter_map(|child| brands.get(*child).ok())
+            .map(|(brand, _, _, texture, atlas, text)| {
+                let natural = match (atlas, texture) {
+                    (Some(atlas), _) => layouts
+                        .get(&atlas.layout)
+                        .and_then(|l| l.textures.get(atlas.index))
+                        .map(|rect| rect.size().as_vec2()),
+                    (None, Some(texture)) => images.get(texture).map(Image::size_f32),
+                    (None, None) => text.map(|t| t.logical_size),
+                };
+                brand.size(size, natural)
+            })
+            .collect::<Vec<_>>();
+        let positions = world_layout(
+            screen,
+            size,
+            &sizes
+                .iter()
+                .map(|s| s.unwrap_or_default())
+                .collect::<Vec<_>>(),
+        );
+
+        let mut brands = brands.iter_many_mut(children);
+        let mut i = 0;
+        while let Some((_, mut transform, sprite, ..)) = brands.fetch_next() {
+            let position = positions[i].extend(transform.translation.z);
+            if transform.translation != position {
+                transform.translation = position;
+            }
+            if let Some(mut sprite) = sprite.filter(|s| s.custom_size != sizes[i]) {
+                sprite.custom_size = sizes[i];
+            }
+            i += 1;
+        }
+    }
+}
+
+//
+// Fill the size the brands are laid out on with the background color of the screen
+//
+pub(crate) fn update_world_background(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    sequences: Query<(&SplashSequence, &SplashBackground)>,
+    mut backgrounds: Query<(&Parent, &mut Sprite), With<SplashWorldBackground>>,
+) {
+    let window = windows.get_single().ok();
+    for (parent, mut sprite) in backgrounds.iter_mut() {
+        let Ok((sequence, data)) = sequences.get(parent.get()) else {
+            continue;
+        };
+        let color = data.screen_colors[sequence.screen];
+        if sprite.color != color {
+            sprite.color = color;
+        }
+        let size = Some(world_size(sequence, window));
+        if sprite.custom_size != size {
+            sprite.custom_size = size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Vec2 = Vec2::new(800., 600.);
+
+    fn screen(splash_type: SplashType, brands: usize) -> SplashScreen {
+        SplashScreen {
+            brands: vec![SplashItem::default(); brands],
+            splash_type,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn layout_list() {
+        let sizes = [Vec2::new(100., 50.), Vec2::new(100., 30.)];
+        let positions = world_layout(&screen(SplashType::List, 2), WINDOW, &sizes);
+        assert_eq!(positions, [Vec2::new(0., 23.), Vec2::new(0., -33.)]);
+    }
+
+    #[cfg(feature = "ui")]
+    #[test]
+    fn layout_grid_gap() {
+        let mut grid = screen(SplashType::Grid, 2);
+        grid.style = crate::SplashStyle::new(|style| style.column_gap = Val::Px(10.));
+        let positions = world_layout(&grid, WINDOW, &[Vec2::splat(40.); 2]);
+        assert_eq!(positions, [Vec2::new(-25., 0.), Vec2::new(25., 0.)]);
+    }
+
+    #[test]
+    fn layout_anchors() {
+        let mut free = screen(SplashType::Free, 2);
+        free.brands[0].anchor = Some(SplashAnchor::TopLeft);
+        free.brands[0].anchor_offset = (Val::Px(10.), Val::Px(20.));
+        let positions = world_layout(&free, WINDOW, &[Vec2::new(100., 50.); 2]);
+        assert_eq!(positions, [Vec2::new(-340., 255.), Vec2::ZERO]);
+    }
+
+    #[test]
+    fn brand_size_per_axis() {
+        let brand = |width, height| SplashItem {
+            width,
+            height,
+            ..default()
+        };
+        let image = Some(Vec2::new(200., 50.));
+
+        let size = SplashWorldBrand::new(&brand(Val::Px(100.), Val::Auto));
+        assert_eq!(size.size(WINDOW, None), None);
+        assert_eq!(size.size(WINDOW, image), Some(Vec2::new(100., 50.)));
+
+        let size = SplashWorldBrand::new(&brand(Val::Auto, Val::Percent(50.)));
+        assert_eq!(size.size(WINDOW, image), Some(Vec2::new(200., 300.)));
+        // Follows the size the splash is laid out on
+        assert_eq!(size.size(WINDOW / 2., image), Some(Vec2::new(200., 150.)));
+
+        let size = SplashWorldBrand::new(&brand(Val::Px(100.), Val::Px(20.)));
+        assert_eq!(size.size(WINDOW, None), Some(Vec2::new(100., 20.)));
+    }
+}